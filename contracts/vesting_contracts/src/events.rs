@@ -0,0 +1,536 @@
+use soroban_sdk::{contractevent, Address, BytesN, String, Symbol, Vec};
+
+use crate::{AutoClaimPolicy, DelegatePermissions, KeeperFeeConfig, Milestone, PauseScope};
+
+// Version of the event payloads below. Every event carries it as `version`
+// so indexers can tell layouts apart. Bump it whenever a published event
+// gains or loses a field, or a field changes meaning or unit; new events
+// simply carry the current version.
+//
+// 2: `milestones_set` carries the milestones; `vault_initialized` added.
+// 3: `keeper_fee_sponsored.amount` is in tokens, not shares.
+pub const EVENT_VERSION: u32 = 3;
+
+// ---------------------------------------------------------------------------
+// Contract lifecycle & migration
+// ---------------------------------------------------------------------------
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractUpgraded {
+    pub new_wasm_hash: BytesN<32>,
+    pub schema_version: u32,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SchemaMigrated {
+    pub from_version: u32,
+    pub to_version: u32,
+    pub version: u32,
+}
+
+// A stored vault record was rewritten to the current schema on first access
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VaultMigrated {
+    #[topic]
+    pub vault_id: u64,
+    pub from_version: u32,
+    pub to_version: u32,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LiquidityMigrated {
+    #[topic]
+    pub token: Address,
+    pub target: Address,
+    pub amount: i128,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractDeprecated {
+    pub target: Address,
+    pub timestamp: u64,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PauseToggled {
+    pub paused: bool,
+    pub timestamp: u64,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PauseScheduled {
+    pub expires_at: u64,
+    pub timestamp: u64,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScopePauseSet {
+    pub scope: PauseScope,
+    pub paused: bool,
+    pub timestamp: u64,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RescueExecuted {
+    #[topic]
+    pub token: Address,
+    pub amount: i128,
+    pub recipient: Address,
+    pub version: u32,
+}
+
+// ---------------------------------------------------------------------------
+// Vault creation & metadata
+// ---------------------------------------------------------------------------
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VaultCreated {
+    #[topic]
+    pub vault_id: u64,
+    #[topic]
+    pub owner: Address,
+    pub total_amount: i128,
+    pub keeper_fee: i128,
+    pub title: String,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub cliff_duration: u64,
+    pub step_duration: u64,
+    pub creation_time: u64,
+    pub is_initialized: bool,
+    pub is_irrevocable: bool,
+    pub is_transferable: bool,
+    pub version: u32,
+}
+
+// A lazily created vault had its metadata written on first access
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VaultInitialized {
+    #[topic]
+    pub vault_id: u64,
+    pub owner: Address,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VaultTitleSet {
+    #[topic]
+    pub vault_id: u64,
+    pub title: String,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BeneficiaryUpdated {
+    #[topic]
+    pub vault_id: u64,
+    pub old_owner: Address,
+    pub new_owner: Address,
+    pub timestamp: u64,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BeneficiaryRotated {
+    #[topic]
+    pub vault_id: u64,
+    pub old_owner: Address,
+    pub new_owner: Address,
+    pub timestamp: u64,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutAddressSet {
+    #[topic]
+    pub owner: Address,
+    pub payout: Option<Address>,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DelegateUpdated {
+    #[topic]
+    pub vault_id: u64,
+    pub old_delegate: Option<Address>,
+    pub new_delegate: Option<Address>,
+    pub version: u32,
+}
+
+// `permissions = None` restores the default: unlimited claims, no staking
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DelegatePermissionsSet {
+    #[topic]
+    pub vault_id: u64,
+    #[topic]
+    pub delegate: Address,
+    pub permissions: Option<DelegatePermissions>,
+    pub version: u32,
+}
+
+// A delegate joined the vault's delegate set
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DelegateAdded {
+    #[topic]
+    pub vault_id: u64,
+    #[topic]
+    pub delegate: Address,
+    pub permissions: Option<DelegatePermissions>,
+    pub version: u32,
+}
+
+// A delegate left the vault's delegate set; a change of primary delegate
+// also emits DelegateUpdated
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DelegateRemoved {
+    #[topic]
+    pub vault_id: u64,
+    #[topic]
+    pub delegate: Address,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VaultArchived {
+    #[topic]
+    pub vault_id: u64,
+    pub owner: Address,
+    pub total_amount: i128,
+    pub released_amount: i128,
+    pub title: String,
+    pub timestamp: u64,
+    pub version: u32,
+}
+
+// ---------------------------------------------------------------------------
+// Claims & staking
+// ---------------------------------------------------------------------------
+
+// Emitted by every claim path. `shares` is what left the vault's balance,
+// `amount` the tokens actually transferred to `recipient` after yield.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokensClaimed {
+    #[topic]
+    pub vault_id: u64,
+    pub claimant: Address,
+    pub recipient: Address,
+    pub shares: i128,
+    pub amount: i128,
+    pub unstaked: i128,
+    pub released_total: i128,
+    pub timestamp: u64,
+    pub version: u32,
+}
+
+// Keeper's cut of an `auto_claim`, paid on top of the `TokensClaimed` shares
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KeeperFeePaid {
+    #[topic]
+    pub vault_id: u64,
+    #[topic]
+    pub keeper: Address,
+    pub shares: i128,
+    pub amount: i128,
+    pub version: u32,
+}
+
+// Summary of a `batch_auto_claim` call; `fee_amount` is the single fee
+// transfer to the keeper, which also emits `KeeperFeePaid` per vault
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BatchAutoClaimed {
+    #[topic]
+    pub keeper: Address,
+    pub vault_count: u32,
+    pub claimed_count: u32,
+    pub fee_amount: i128,
+    pub version: u32,
+}
+
+// `public_key = None` means relayed claims are disabled for the vault
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimSignerSet {
+    #[topic]
+    pub vault_id: u64,
+    pub public_key: Option<BytesN<32>>,
+    pub version: u32,
+}
+
+// A relayer submitted a signed claim; `relayer_fee` is in shares and the
+// claim itself is reported by `TokensClaimed`
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SignedClaimRelayed {
+    #[topic]
+    pub vault_id: u64,
+    #[topic]
+    pub relayer: Address,
+    pub nonce: u64,
+    pub relayer_fee: i128,
+    pub version: u32,
+}
+
+// `policy = None` means the owner cleared it and any keeper may claim again
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AutoClaimPolicySet {
+    #[topic]
+    pub vault_id: u64,
+    pub policy: Option<AutoClaimPolicy>,
+    pub version: u32,
+}
+
+// Keeper's fee for a sponsored vault, credited to their ledger from the
+// reward pool instead of being taken from the claim
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KeeperFeeSponsored {
+    #[topic]
+    pub vault_id: u64,
+    #[topic]
+    pub keeper: Address,
+    pub amount: i128,
+    pub pool_balance: i128,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KeeperFeesWithdrawn {
+    #[topic]
+    pub keeper: Address,
+    pub amount: i128,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KeeperPoolFunded {
+    pub amount: i128,
+    pub pool_balance: i128,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KeeperPoolWithdrawn {
+    pub amount: i128,
+    pub pool_balance: i128,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KeeperSponsorshipSet {
+    #[topic]
+    pub vault_id: u64,
+    pub sponsored: bool,
+    pub version: u32,
+}
+
+// Keeper fee schedule changed; `vault_id = None` is the global default and
+// `config = None` clears it
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KeeperFeeConfigured {
+    pub vault_id: Option<u64>,
+    pub config: Option<KeeperFeeConfig>,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimCallbackInvoked {
+    #[topic]
+    pub vault_id: u64,
+    pub target_contract: Address,
+    pub function: Symbol,
+    pub amount: i128,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokensStaked {
+    #[topic]
+    pub vault_id: u64,
+    pub validator: Address,
+    pub amount: i128,
+    pub staked_total: i128,
+    pub version: u32,
+}
+
+// ---------------------------------------------------------------------------
+// Revocation & freezing
+// ---------------------------------------------------------------------------
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokensRevoked {
+    #[topic]
+    pub vault_id: u64,
+    pub beneficiary: Address,
+    pub amount: i128,
+    pub released_total: i128,
+    pub timestamp: u64,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BatchRevoked {
+    pub vault_count: u32,
+    pub total_returned: i128,
+    pub timestamp: u64,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VaultClawedBack {
+    #[topic]
+    pub vault_id: u64,
+    pub owner: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VaultFrozen {
+    #[topic]
+    pub vault_id: u64,
+    pub reason_code: u32,
+    pub reference_hash: Option<BytesN<32>>,
+    pub frozen_by: Address,
+    pub expires_at: Option<u64>,
+    pub timestamp: u64,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VaultUnfrozen {
+    #[topic]
+    pub vault_id: u64,
+    pub timestamp: u64,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IrrevocableMarked {
+    #[topic]
+    pub vault_id: u64,
+    pub timestamp: u64,
+    pub version: u32,
+}
+
+// ---------------------------------------------------------------------------
+// Milestones
+// ---------------------------------------------------------------------------
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MilestonesSet {
+    #[topic]
+    pub vault_id: u64,
+    pub milestones: Vec<Milestone>,
+    pub milestone_count: u32,
+    pub total_percentage: u32,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MilestoneUnlocked {
+    #[topic]
+    pub vault_id: u64,
+    pub milestone_id: u64,
+    pub timestamp: u64,
+    pub version: u32,
+}
+
+// ---------------------------------------------------------------------------
+// Cohorts
+// ---------------------------------------------------------------------------
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CohortCreated {
+    #[topic]
+    pub cohort_id: u64,
+    pub vault_ids: Vec<u64>,
+    pub timestamp: u64,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CohortFrozen {
+    #[topic]
+    pub cohort_id: u64,
+    pub affected: u32,
+    pub reason_code: u32,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CohortIrrevocableMarked {
+    #[topic]
+    pub cohort_id: u64,
+    pub affected: u32,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CohortMilestoneUnlocked {
+    #[topic]
+    pub cohort_id: u64,
+    pub milestone_id: u64,
+    pub affected: u32,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CohortRevoked {
+    #[topic]
+    pub cohort_id: u64,
+    pub total_returned: i128,
+    pub timestamp: u64,
+    pub version: u32,
+}
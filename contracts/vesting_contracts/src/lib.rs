@@ -1,22 +1,55 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl, contracttype, token, vec, Address, Env, IntoVal, Map, Symbol, Val, Vec, String};
-use soroban_sdk::{contract, contractimpl, contracttype, token, vec, Address, Env, IntoVal, Map, Symbol, Vec, String};
-
+#![allow(clippy::too_many_arguments)]
 use soroban_sdk::{
-    contract, contractimpl, contracttype, token, vec, Address, Env, IntoVal, Map, String, Symbol,
-    Vec,
+    contract, contracterror, contractimpl, contracttype, token, vec, xdr::ToXdr, Address, BytesN,
+    Env, IntoVal, Map, String, Symbol, TryFromVal, Val, Vec,
 };
 
 // 10 years in seconds (Issue #44)
 pub const MAX_DURATION: u64 = 315_360_000;
 
+// Ledgers per day at a ~5s close time, used for TTL management
+const DAY_IN_LEDGERS: u32 = 17_280;
+
+// Vault-level entries live in persistent storage and are topped back up to
+// ~120 days of TTL whenever they are written with less than ~30 days left.
+pub const PERSISTENT_TTL_THRESHOLD: u32 = 30 * DAY_IN_LEDGERS;
+pub const PERSISTENT_TTL_EXTEND_TO: u32 = 120 * DAY_IN_LEDGERS;
+
+// Instance storage only holds global config and counters
+pub const INSTANCE_TTL_THRESHOLD: u32 = 30 * DAY_IN_LEDGERS;
+pub const INSTANCE_TTL_EXTEND_TO: u32 = 120 * DAY_IN_LEDGERS;
+
+// Largest page returned by paginated queries
+pub const MAX_PAGE_SIZE: u32 = 100;
+// Upper bound on vault IDs inspected by a single `list_vaults` call
+pub const MAX_LIST_SCAN: u32 = 500;
+
+// Storage schema written by this build. Bump when a stored layout changes
+// and teach `try_load_vault` (or the relevant loader) to convert the old one.
+pub const SCHEMA_VERSION: u32 = 2;
+
+// Fields in the current `Vault`; a stored record with fewer is an older layout
+const VAULT_FIELD_COUNT: u32 = 15;
+
+// Most delegates a single vault can have
+pub const MAX_DELEGATES: u32 = 10;
+
+// Longest emergency pause that can be set in one call (30 days)
+pub const MAX_PAUSE_DURATION: u64 = 2_592_000;
+
+// Keeper fee rates are expressed in basis points of the claimed shares
+pub const MAX_KEEPER_FEE_BPS: u32 = 10_000;
+
 // DataKey for whitelisted tokens
 #[contracttype]
 pub enum WhitelistDataKey {
     WhitelistedTokens,
 }
 
-// DataKey for contract storage
+// DataKey for contract storage. Global config and counters are kept in
+// instance storage; per-vault, per-user and per-keeper records are kept in
+// persistent storage (see `set_persistent`).
 #[contracttype]
 pub enum DataKey {
     AdminAddress,
@@ -28,40 +61,322 @@ pub enum DataKey {
     VaultMilestones(u64),
     UserVaults(Address),
     IsPaused,
-    KeeperFees,
-    UserVaults(Address),
-    VaultMilestones(u64),
-    UserVaults(Address),
-    KeeperFees,
-    IsPaused,
+    PauseExpiry, // Timestamp at which a time-boxed pause lapses
+    ScopePaused(PauseScope),
+    VaultFreeze(u64),
+    FrozenVaults,
+    AuditLogCount,
+    AuditEntry(u64),
+    VaultAuditLog(u64),                // Audit entry ids touching a vault
+    KeeperFees(Address),               // Legacy fee total, folded into KeeperLedger on first write
+    KeeperLedger(Address),             // Per-keeper fee ledger
+    KeeperPool,                        // Admin-funded tokens for sponsored keeper fees
+    KeeperOwed,                        // Sponsored fees credited to keepers but not yet withdrawn
+    KeeperSponsored(u64),              // Vault's keeper fees are paid from the pool
+    AutoClaimPolicy(u64),              // Owner's limits on auto_claim for a vault
+    LastAutoClaim(u64),                // Timestamp of the vault's last keeper claim
+    DefaultKeeperFee,                  // KeeperFeeConfig applied when a vault sets none
+    VaultKeeperFee(u64),               // Per-vault KeeperFeeConfig override
+    VaultLiveUntil(u64),               // Ledger up to which a vault's records are guaranteed live
+    ArchivedVault(u64),                // Compact summary left behind by archive_vault
+    DelegateVaults(Address),           // Vault IDs a delegate may claim for
+    VaultDelegates(u64),               // Every delegate of a vault, primary first
+    DelegatePermissions(u64, Address), // Limits on a vault's delegate
+    DelegateUsage(u64, Address),       // Delegate's claims in the current cap period
+    PayoutAddress(Address),            // Owner's default claim recipient
+    ClaimSigner(u64),                  // ed25519 key that may sign relayed claims
+    ClaimNonce(u64),                   // Next nonce a signed claim must carry
+    CohortCount,
+    CohortVaults(u64), // Vault IDs created by one batch call
+    VaultCohort(u64),  // Cohort a vault was created in
     IsDeprecated,
     MigrationTarget,
-    Token,        // yield-bearing token
-    TotalShares,  // remaining initial_deposit_shares
+    Token,       // yield-bearing token
+    TotalShares, // remaining initial_deposit_shares
     TotalStaked,
+    TotalReleased, // claimed by beneficiaries, before yield
+    TotalRevoked,  // returned to admin by revoke or clawback
+    SchemaVersion,
+    WasmHash,      // Code hash installed by the last `upgrade`
+    TotalsRecount, // Vaults `recount_totals` still has to visit after a migration
+}
+
+/// Every failure the vesting contract can return. Codes are stable and listed in SPEC.md;
+/// new variants get new numbers and existing ones are never renumbered.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    // Admin and lifecycle
+    AdminNotSet = 1,
+    NoProposedAdmin = 2,
+    ContractDeprecated = 3,
+    ContractPaused = 4,
+    ClaimsPaused = 5,
+    VaultCreationPaused = 6,
+    StakingPaused = 7,
+    TransfersPaused = 8,
+    KeeperPaused = 9,
+    InvalidPauseDuration = 10,
+    PauseDurationTooLong = 11,
+    SchemaUpToDate = 12,
+    AlreadyInitialized = 13,
+    MigrationPending = 14,
+
+    // Tokens and treasury
+    TokenNotSet = 20,
+    TokenAlreadySet = 21,
+    TokenNotWhitelisted = 22,
+    CannotRescueYieldToken = 23,
+    NoUnallocatedTokens = 24,
+    InvalidMigrationTarget = 25,
+    InsufficientAdminBalance = 26,
+    StakingContractNotSet = 27,
+
+    // Vault state
+    VaultNotFound = 30,
+    VaultNotInitialized = 31,
+    VaultFrozen = 32,
+    VaultAlreadyFrozen = 33,
+    VaultNotFrozen = 34,
+    VaultIrrevocable = 35,
+    VaultAlreadyIrrevocable = 36,
+    VaultNotTransferable = 37,
+    InvalidSchedule = 38,
+    DurationTooLong = 39,
+    TitleTooLong = 40,
+    InvalidFreezeExpiry = 41,
+    UnrecognizedVaultLayout = 42,
+    VaultNotFullyReleased = 43,
+    VaultHasStake = 44,
+    CohortNotFound = 45,
+    NoDelegate = 46,
+    DelegateNotPermitted = 47,
+    DelegateExpired = 48,
+    DelegateCapExceeded = 49,
+
+    // Amounts
+    InvalidAmount = 50,
+    NothingToClaim = 51,
+    InsufficientUnlockedTokens = 52,
+    NothingToRevoke = 53,
+    AmountExceedsUnvested = 54,
+    InsufficientStakeableFunds = 55,
+    InsufficientClaimableForFee = 56,
+    GracePeriodExpired = 57,
+    TokensAlreadyClaimed = 58,
+    InvalidRecipient = 59,
+    InvalidKeeperFee = 60,
+    KeeperPoolInsufficient = 61,
+    NothingToWithdraw = 62,
+    InvalidBatch = 63,
+
+    // Milestones
+    MilestonesNotConfigured = 70,
+    NoMilestones = 71,
+    InvalidMilestonePercentage = 72,
+    MilestonePercentageTooLarge = 73,
+    MilestoneTotalExceeds100 = 74,
+    DuplicateMilestone = 75,
+    MilestoneNotFound = 76,
+    MilestoneAlreadyUnlocked = 77,
+
+    // Auto-claim policy
+    AutoClaimDisabled = 80,
+    KeeperNotPermitted = 81,
+    AutoClaimTooSoon = 82,
+    AutoClaimBelowMinimum = 83,
+
+    // Delegate sets
+    TooManyDelegates = 90,
+    DelegateAlreadyAdded = 91,
+
+    // Signed claims
+    NoClaimSigner = 100,
+    InvalidNonce = 101,
+    SignedClaimExpired = 102,
+    InvalidSignature = 103,
+}
+
+/// Independently pausable flows. The global `IsPaused` flag additionally
+/// halts every withdrawal flow (`Claims` and `Keeper`).
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PauseScope {
+    Claims,
+    VaultCreation,
+    Staking,
+    Transfers,
+    Keeper,
+}
+
+/// Running totals across all vaults, as returned by `get_accounting_totals`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AccountingTotals {
+    pub locked: i128,
+    pub released: i128,
+    pub revoked: i128,
+    pub staked: i128,
+}
+
+/// Range of vault IDs whose balances are not yet in the accounting totals,
+/// as returned by `get_totals_recount`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TotalsRecount {
+    pub next_vault_id: u64,
+    pub last_vault_id: u64,
+}
+
+/// Effective pause state of every scope, as returned by `get_pause_matrix`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PauseMatrix {
+    pub global: bool,
+    pub claims: bool,
+    pub vault_creation: bool,
+    pub staking: bool,
+    pub transfers: bool,
+    pub keeper: bool,
+}
+
+/// Keeper fee schedule: `bps` of the claimed shares, clamped to `[min_fee, max_fee]`.
+/// Claims too small to cover the fee are auto-claimed fee-free.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KeeperFeeConfig {
+    pub bps: u32,
+    pub min_fee: i128,
+    pub max_fee: i128,
+}
+
+/// Per-keeper fee ledger, as returned by `get_keeper_ledger`. Amounts are in
+/// tokens: `deducted` counts fees taken from beneficiaries' claims and paid out
+/// immediately; `sponsored` counts fees credited from the reward pool, of which
+/// `withdrawn` have been withdrawn.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KeeperLedger {
+    pub deducted: i128,
+    pub sponsored: i128,
+    pub withdrawn: i128,
+    pub claims: u32, // Successful auto-claims, fee-free ones included
+}
+
+/// What a vault's delegate may do, set with `set_delegate_permissions`.
+/// A delegate without permissions may claim without limit but not stake.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DelegatePermissions {
+    pub can_claim: bool,
+    pub can_stake: bool,
+    pub claim_cap: Option<i128>, // Shares claimable per period; None = unlimited
+    pub period: u64,             // Cap window in seconds; 0 = one window for the whole grant
+    pub expires_at: Option<u64>, // Delegate loses every right at this timestamp
+}
+
+/// Shares a delegate has claimed in the cap window starting at `period_start`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DelegateUsage {
+    pub period_start: u64,
+    pub claimed: i128,
+}
+
+/// Owner's limits on `auto_claim` for one vault, set with `set_auto_claim_policy`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AutoClaimPolicy {
+    pub enabled: bool,
+    pub min_interval: u64,             // Seconds between keeper claims
+    pub min_claim: i128,               // Smallest claim, in shares, a keeper may trigger
+    pub allowed_keepers: Vec<Address>, // Empty = any keeper
+}
+
+/// Off-chain claim the beneficiary signs for `claim_with_signature`. The
+/// signature covers the XDR of `(contract address, SignedClaim)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SignedClaim {
+    pub vault_id: u64,
+    pub amount: i128, // Shares to claim, relayer fee included
+    pub nonce: u64,
+    pub expires_at: u64,
+    pub recipient: Address,
+    pub relayer_fee: i128, // Shares paid to the relayer out of `amount`
+}
+
+/// Keeper reward pool, as returned by `get_keeper_pool`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KeeperPool {
+    pub balance: i128, // Available for future sponsored fees
+    pub owed: i128,    // Credited to keepers, awaiting withdrawal
+}
+
+/// Optional filters for `list_vaults`; `None` matches every vault.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VaultFilter {
+    pub owner: Option<Address>,
+    pub frozen: Option<bool>,
+    pub irrevocable: Option<bool>,
+    pub fully_released: Option<bool>,
+}
+
+/// One page of `list_vaults` or `list_frozen_vaults` results. `next_cursor` is `None` once
+/// everything has been scanned.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VaultPage {
+    pub vault_ids: Vec<u64>,
+    pub next_cursor: Option<u64>,
+}
+
+// Who is driving a claim through `execute_claim`. `Delegate(None)` is the
+// vault's primary delegate; a `Relayer` submits a claim the owner signed.
+// `AuthorizedOwner` names an owner whose auth the caller already required.
+enum ClaimPath {
+    Owner,
+    AuthorizedOwner(Address),
+    Delegate(Option<Address>),
+    Keeper(Address),
+    Relayer(Address, i128),
+}
+
+// Who is driving a stake through `internal_stake`
+enum StakePath {
+    Owner,
+    Delegate(Option<Address>),
+}
+
+// Result of `execute_claim`: shares released to the recipient and the tokens
+// they were converted to (keeper fee excluded), plus the keeper's fee in
+// shares. Every claim entry point returns `shares`; `amount` differs from it
+// only once yield has accrued.
+struct ClaimOutcome {
+    shares: i128,
+    amount: i128,
+    keeper_fee: i128,
 }
 
+mod events;
 mod factory;
+pub use events::*;
 pub use factory::{VestingFactory, VestingFactoryClient};
 
 #[contract]
 pub struct VestingContract;
 
 /// Vault structure with lazy initialization
-/// 
+///
 /// Supports both linear and periodic vesting schedules:
 /// - Linear vesting (step_duration = 0): Tokens vest continuously over time
 /// - Periodic vesting (step_duration > 0): Tokens vest in discrete steps (e.g., monthly)
-/// 
+///
 /// For periodic vesting, the calculation rounds down to the nearest completed step,
 /// ensuring users only receive tokens that have fully vested according to the step schedule.
-// Vault structure with lazy initialization
-    Token,       // yield-bearing token
-    TotalShares, // remaining initial_deposit_shares
-    TotalStaked,
-}
-
-// Vault structure with lazy initialization
 #[contracttype]
 #[derive(Clone)]
 pub struct Vault {
@@ -72,33 +387,19 @@ pub struct Vault {
 
     pub owner: Address,
     pub delegate: Option<Address>, // Optional delegate address for claiming
-    pub total_amount: i128,
-    pub released_amount: i128,
+    pub title: String,             // Short human-readable title (max 32 chars)
     pub start_time: u64,
     pub end_time: u64,
     pub creation_time: u64, // Timestamp of creation for clawback grace period
     /// Duration of each vesting step in seconds (0 = linear vesting)
-    /// 
+    ///
     /// Common values:
     /// - 0: Linear vesting (continuous)
     /// - 2,592,000: Monthly (30 days)
-    /// - 7,776,000: Quarterly (90 days) 
+    /// - 7,776,000: Quarterly (90 days)
     /// - 31,536,000: Yearly (365 days)
     pub step_duration: u64,
 
-    pub is_initialized: bool,  // Lazy initialization flag
-    pub is_irrevocable: bool,  // Security flag to prevent admin withdrawal
-    pub is_transferable: bool, // Can the beneficiary transfer this vault?
-    pub keeper_fee: i128, // Fee paid to anyone who triggers auto_claim
-        pub title: String, // Short human-readable title (max 32 chars)
-    pub is_initialized: bool, // Lazy initialization flag
-    pub is_irrevocable: bool, // Security flag to prevent admin withdrawal
-    pub creation_time: u64, // Timestamp of creation for clawback grace period
-    pub is_transferable: bool, // Can the beneficiary transfer this vault?
-    pub is_frozen: bool, // Individual vault freeze flag for security investigations
-    pub creation_time: u64, // Timestamp of creation for clawback grace period
-    pub step_duration: u64, // Duration of each vesting step in seconds (0 = linear)
-
     pub is_initialized: bool,  // Lazy initialization flag
     pub is_irrevocable: bool,  // Security flag to prevent admin withdrawal
     pub is_transferable: bool, // Can the beneficiary transfer this vault?
@@ -106,13 +407,72 @@ pub struct Vault {
 }
 
 #[contracttype]
-#[derive(Clone)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Milestone {
     pub id: u64,
     pub percentage: u32,
     pub is_unlocked: bool,
 }
 
+/// Investigation details attached to a frozen vault
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FreezeRecord {
+    pub reason_code: u32,
+    pub reference_hash: Option<BytesN<32>>, // Hash of the off-chain case file
+    pub frozen_by: Address,
+    pub frozen_at: u64,
+    pub expires_at: Option<u64>, // Freeze lapses automatically at this timestamp
+}
+
+/// Summary kept for a completed vault after `archive_vault` removes its full record
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ArchivedVault {
+    pub owner: Address,
+    pub total_amount: i128,
+    pub title: String,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub creation_time: u64,
+    pub archived_at: u64,
+}
+
+/// Privileged actions recorded in the audit log
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AdminAction {
+    VaultCreated,
+    TokensRevoked,
+    VaultClawedBack,
+    VaultFrozen,
+    VaultUnfrozen,
+    MilestonesSet,
+    MilestoneUnlocked,
+    BeneficiaryTransferred,
+    TitleSet,
+    IrrevocableMarked,
+    TokensRescued,
+    ContractUpgraded,
+    KeeperFeeSet,
+    KeeperPoolFunded,
+    KeeperPoolWithdrawn,
+    KeeperSponsorshipSet,
+}
+
+/// One append-only audit log entry. `params` holds the action's arguments
+/// in the order documented for each `AdminAction` in SPEC.md.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct AuditEntry {
+    pub id: u64,
+    pub action: AdminAction,
+    pub actor: Address,
+    pub vault_id: Option<u64>,
+    pub timestamp: u64,
+    pub params: Vec<Val>,
+}
+
 #[contracttype]
 pub struct BatchCreateData {
     pub recipients: Vec<Address>,
@@ -123,55 +483,265 @@ pub struct BatchCreateData {
     pub step_durations: Vec<u64>,
 }
 
+/// One vault's part of a `claim_all` call
 #[contracttype]
-pub struct TokensRevoked {
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VaultClaim {
     pub vault_id: u64,
-    pub vested_amount: i128,
-    pub unvested_amount: i128,
-    pub beneficiary: Address,
-    pub timestamp: u64,
+    pub token: Address,
+    pub shares: i128,
+    pub amount: i128, // Tokens paid out, yield included
 }
 
+/// Why a vault was or wasn't claimed in a `batch_auto_claim` call
 #[contracttype]
-pub struct VaultCreated {
-    pub vault_id: u64,
-    pub beneficiary: Address,
-    pub total_amount: i128,
-    pub cliff_duration: u64,
-    pub start_time: u64,
-    pub title: String,
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AutoClaimStatus {
+    Claimed,
+    Frozen,
+    NothingToClaim,
+    PoolExhausted,  // Sponsored vault whose fee the reward pool can't cover
+    PolicyBlocked,  // The owner's auto-claim policy doesn't allow this claim
+    NotFound,       // No vault with this id
+    NotInitialized, // Lazy vault whose metadata hasn't been written yet
 }
 
-// mod factory;
-// pub use factory::{VestingFactory, VestingFactoryClient};
-
+/// One vault's part of a `batch_auto_claim` call. Amounts are zero unless `status` is `Claimed`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AutoClaimResult {
+    pub vault_id: u64,
+    pub status: AutoClaimStatus,
+    pub shares: i128,     // Released to the beneficiary
+    pub amount: i128,     // Tokens paid to the beneficiary, yield included
+    pub keeper_fee: i128, // Shares earned by the keeper
+}
 
 #[contractimpl]
 #[allow(deprecated)]
 impl VestingContract {
-    fn require_not_deprecated(env: &Env) {
+    fn require_not_deprecated(env: &Env) -> Result<(), Error> {
         let deprecated: bool = env
             .storage()
             .instance()
             .get(&DataKey::IsDeprecated)
             .unwrap_or(false);
         if deprecated {
-            panic!("Contract is deprecated");
+            return Err(Error::ContractDeprecated);
         }
+
+        Ok(())
     }
 
-    fn require_valid_duration(start_time: u64, end_time: u64) {
+    fn require_valid_duration(start_time: u64, end_time: u64) -> Result<(), Error> {
         let duration = end_time
             .checked_sub(start_time)
-            .unwrap_or_else(|| panic!("end_time must be >= start_time"));
+            .ok_or(Error::InvalidSchedule)?;
         if duration > MAX_DURATION {
-            panic!("duration exceeds MAX_DURATION");
+            return Err(Error::DurationTooLong);
+        }
+
+        Ok(())
+    }
+
+    // Every per-vault list of a batch needs one entry per recipient;
+    // `step_durations` may be shorter, missing entries meaning linear vesting
+    fn require_valid_batch(batch_data: &BatchCreateData) -> Result<(), Error> {
+        let count = batch_data.recipients.len();
+        if batch_data.amounts.len() != count
+            || batch_data.start_times.len() != count
+            || batch_data.end_times.len() != count
+            || batch_data.keeper_fees.len() != count
+            || batch_data.step_durations.len() > count
+        {
+            return Err(Error::InvalidBatch);
+        }
+        Ok(())
+    }
+
+    // Write a vault-level record to persistent storage and extend its TTL,
+    // together with the instance entry holding global config
+    fn set_persistent<V: IntoVal<Env, Val>>(env: &Env, key: &DataKey, value: &V) {
+        env.storage().persistent().set(key, value);
+        env.storage().persistent().extend_ttl(
+            key,
+            PERSISTENT_TTL_THRESHOLD,
+            PERSISTENT_TTL_EXTEND_TO,
+        );
+        Self::extend_instance_ttl(env);
+    }
+
+    // Read a persistent record, first moving it out of instance storage if a
+    // deployment from before schema 2 left it there
+    fn get_persistent<V>(env: &Env, key: &DataKey) -> Option<V>
+    where
+        V: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+    {
+        if let Some(value) = env.storage().persistent().get(key) {
+            return Some(value);
+        }
+        let value: V = env.storage().instance().get(key)?;
+        env.storage().instance().remove(key);
+        Self::set_persistent(env, key, &value);
+        Some(value)
+    }
+
+    // Bump an existing persistent entry to the full TTL, whatever it has left
+    fn extend_persistent(env: &Env, key: &DataKey) {
+        env.storage().persistent().extend_ttl(
+            key,
+            PERSISTENT_TTL_EXTEND_TO,
+            PERSISTENT_TTL_EXTEND_TO,
+        );
+    }
+
+    fn extend_instance_ttl(env: &Env) {
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_TTL_THRESHOLD, INSTANCE_TTL_EXTEND_TO);
+    }
+
+    // Extend the TTL of a vault and the records it reaches (milestones, owner
+    // and delegate indexes, freeze record, audit index, cohort). Audit entries
+    // are extended a page at a time by `extend_vault_audit_ttl`. Anyone may
+    // call this so that long grants never depend on the admin to stay live.
+    // Returns the guaranteed remaining TTL in ledgers. For an archived vault
+    // the summary and its history are extended instead.
+    pub fn extend_vault_ttl(env: Env, vault_id: u64) -> Result<u32, Error> {
+        let vault: Vault = match Self::load_vault(&env, vault_id) {
+            Ok(vault) => vault,
+            Err(Error::VaultNotFound)
+                if env
+                    .storage()
+                    .persistent()
+                    .has(&DataKey::ArchivedVault(vault_id)) =>
+            {
+                Self::extend_vault_history(&env, vault_id);
+                return Ok(PERSISTENT_TTL_EXTEND_TO);
+            }
+            Err(e) => return Err(e),
+        };
+
+        Self::extend_vault_entries(&env, vault_id, &vault);
+        Self::extend_vault_links(&env, vault_id, &vault);
+        Self::extend_vault_history(&env, vault_id);
+
+        Self::get_vault_ttl(env, vault_id)
+    }
+
+    // Extend a page of the vault's audit entries, oldest first, to the full
+    // TTL. Works on archived vaults too. Anyone may call this. Returns the
+    // offset of the next page, or None once the vault's log is exhausted.
+    pub fn extend_vault_audit_ttl(env: Env, vault_id: u64, offset: u32, limit: u32) -> Option<u32> {
+        let audit_ids: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::VaultAuditLog(vault_id))
+            .unwrap_or(Vec::new(&env));
+        let end = offset
+            .saturating_add(limit.min(MAX_PAGE_SIZE))
+            .min(audit_ids.len());
+        for i in offset..end {
+            let key = DataKey::AuditEntry(audit_ids.get(i).unwrap());
+            if env.storage().persistent().has(&key) {
+                Self::extend_persistent(&env, &key);
+            }
+        }
+        if end < audit_ids.len() {
+            Some(end)
+        } else {
+            None
+        }
+    }
+
+    // Minimum number of ledgers the vault's records are guaranteed to stay live.
+    // Contracts cannot read TTLs directly, so this is tracked on every full extension.
+    pub fn get_vault_ttl(env: Env, vault_id: u64) -> Result<u32, Error> {
+        if !env
+            .storage()
+            .persistent()
+            .has(&DataKey::VaultData(vault_id))
+        {
+            return Err(Error::VaultNotFound);
+        }
+        let live_until: u32 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::VaultLiveUntil(vault_id))
+            .unwrap_or(0);
+        Ok(live_until.saturating_sub(env.ledger().sequence()))
+    }
+
+    // Extend the vault's own fixed set of keys. Runs on every creation and
+    // claim, so it must not grow with the vault's history or delegates.
+    fn extend_vault_entries(env: &Env, vault_id: u64, vault: &Vault) {
+        let storage = env.storage().persistent();
+        let keys = [
+            DataKey::VaultData(vault_id),
+            DataKey::VaultMilestones(vault_id),
+            DataKey::UserVaults(vault.owner.clone()),
+            DataKey::PayoutAddress(vault.owner.clone()),
+            DataKey::VaultFreeze(vault_id),
+            DataKey::VaultKeeperFee(vault_id),
+            DataKey::KeeperSponsored(vault_id),
+            DataKey::AutoClaimPolicy(vault_id),
+            DataKey::LastAutoClaim(vault_id),
+            DataKey::VaultDelegates(vault_id),
+            DataKey::ClaimSigner(vault_id),
+            DataKey::ClaimNonce(vault_id),
+        ];
+        for key in keys.iter() {
+            if storage.has(key) {
+                storage.extend_ttl(key, PERSISTENT_TTL_EXTEND_TO, PERSISTENT_TTL_EXTEND_TO);
+            }
+        }
+
+        let live_until = env.ledger().sequence() + PERSISTENT_TTL_EXTEND_TO;
+        Self::set_persistent(env, &DataKey::VaultLiveUntil(vault_id), &live_until);
+    }
+
+    // Shared records the vault appears in: its delegates' indexes, permissions
+    // and usage (at most `MAX_DELEGATES` of each) and the frozen index
+    fn extend_vault_links(env: &Env, vault_id: u64, vault: &Vault) {
+        let storage = env.storage().persistent();
+        for delegate in Self::delegates_of(env, vault_id, vault).iter() {
+            let delegate_keys = [
+                DataKey::DelegateVaults(delegate.clone()),
+                DataKey::DelegatePermissions(vault_id, delegate.clone()),
+                DataKey::DelegateUsage(vault_id, delegate.clone()),
+            ];
+            for key in delegate_keys.iter() {
+                if storage.has(key) {
+                    Self::extend_persistent(env, key);
+                }
+            }
+        }
+        if vault.is_frozen && storage.has(&DataKey::FrozenVaults) {
+            Self::extend_persistent(env, &DataKey::FrozenVaults);
+        }
+    }
+
+    // Records that outlive `archive_vault`: the archived summary, the vault's
+    // audit index and its cohort. The audit entries themselves are paged
+    // through `extend_vault_audit_ttl`.
+    fn extend_vault_history(env: &Env, vault_id: u64) {
+        let storage = env.storage().persistent();
+        let extend = |key: DataKey| {
+            if storage.has(&key) {
+                Self::extend_persistent(env, &key);
+            }
+        };
+        extend(DataKey::ArchivedVault(vault_id));
+        extend(DataKey::VaultAuditLog(vault_id));
+        extend(DataKey::VaultCohort(vault_id));
+        if let Some(cohort_id) = storage.get::<_, u64>(&DataKey::VaultCohort(vault_id)) {
+            extend(DataKey::CohortVaults(cohort_id));
         }
     }
 
     // Admin-only: Add token to whitelist
-    pub fn add_to_whitelist(env: Env, token: Address) {
-        Self::require_admin(&env);
+    pub fn add_to_whitelist(env: Env, token: Address) -> Result<(), Error> {
+        Self::require_admin(&env)?;
         let mut whitelist: Map<Address, bool> = env
             .storage()
             .instance()
@@ -181,6 +751,8 @@ impl VestingContract {
         env.storage()
             .instance()
             .set(&WhitelistDataKey::WhitelistedTokens, &whitelist);
+
+        Ok(())
     }
 
     // Check if token is whitelisted
@@ -193,15 +765,13 @@ impl VestingContract {
         whitelist.get(token.clone()).unwrap_or(false)
     }
 
-    // Initialize contract with initial supply
-    pub fn initialize(env: Env, admin: Address, initial_supply: i128) {
-        Self::require_not_deprecated(&env);
-        env.storage().instance().set(&DataKey::InitialSupply, &initial_supply);
-        env.storage().instance().set(&DataKey::AdminBalance, &initial_supply);
-
-        env.storage().instance().set(&DataKey::InitialSupply, &initial_supply);
-        env.storage().instance().set(&DataKey::AdminBalance, &initial_supply);
-        env.storage().instance().set(&DataKey::AdminAddress, &admin);
+    // Initialize contract with initial supply. Only the first call succeeds;
+    // afterwards the admin can only change through the ownership transfer flow.
+    pub fn initialize(env: Env, admin: Address, initial_supply: i128) -> Result<(), Error> {
+        Self::require_not_deprecated(&env)?;
+        if env.storage().instance().has(&DataKey::AdminAddress) {
+            return Err(Error::AlreadyInitialized);
+        }
         env.storage()
             .instance()
             .set(&DataKey::InitialSupply, &initial_supply);
@@ -216,9 +786,6 @@ impl VestingContract {
         // Initialize pause state to false (unpaused)
         env.storage().instance().set(&DataKey::IsPaused, &false);
 
-        // Initialize whitelisted tokens map
-        let whitelist: Map<Address, bool> = Map::new(&env);
-        env.storage()
         // Initialize deprecated state to false (active)
         env.storage().instance().set(&DataKey::IsDeprecated, &false);
 
@@ -233,43 +800,56 @@ impl VestingContract {
 
         env.storage().instance().set(&DataKey::TotalShares, &0i128);
         env.storage().instance().set(&DataKey::TotalStaked, &0i128);
+        env.storage()
+            .instance()
+            .set(&DataKey::TotalReleased, &0i128);
+        env.storage().instance().set(&DataKey::TotalRevoked, &0i128);
+        env.storage()
+            .instance()
+            .set(&DataKey::SchemaVersion, &SCHEMA_VERSION);
+        Self::extend_instance_ttl(&env);
+
+        Ok(())
     }
 
-    pub fn set_token(env: Env, token: Address) {
-        Self::require_admin(&env);
+    pub fn set_token(env: Env, token: Address) -> Result<(), Error> {
+        Self::require_admin(&env)?;
         if env.storage().instance().has(&DataKey::Token) {
-            panic!("Token already set");
+            return Err(Error::TokenAlreadySet);
         }
         env.storage().instance().set(&DataKey::Token, &token);
+
+        Ok(())
     }
 
-    fn get_token_client(env: &Env) -> token::Client<'_> {
+    fn get_token_client(env: &Env) -> Result<token::Client<'_>, Error> {
         let token: Address = env
             .storage()
             .instance()
-            .set(&WhitelistDataKey::WhitelistedTokens, &whitelist);
+            .get(&DataKey::Token)
+            .ok_or(Error::TokenNotSet)?;
+        Ok(token::Client::new(env, &token))
     }
 
     // Helper function to check if caller is admin
-    fn require_admin(env: &Env) {
+    fn require_admin(env: &Env) -> Result<(), Error> {
         let admin: Address = env
             .storage()
             .instance()
             .get(&DataKey::AdminAddress)
-            .unwrap_or_else(|| panic!("Admin not set"));
+            .ok_or(Error::AdminNotSet)?;
         admin.require_auth();
+
+        Ok(())
     }
 
-    fn require_milestones_configured(env: &Env, vault_id: u64) -> Vec<Milestone> {
-        let milestones: Vec<Milestone> = env
-            .storage()
-            .instance()
-            .get(&DataKey::VaultMilestones(vault_id))
-            .unwrap_or(Vec::new(env));
+    fn require_milestones_configured(env: &Env, vault_id: u64) -> Result<Vec<Milestone>, Error> {
+        let milestones: Vec<Milestone> =
+            Self::get_persistent(env, &DataKey::VaultMilestones(vault_id)).unwrap_or(Vec::new(env));
         if milestones.is_empty() {
-            panic!("Milestones not configured");
+            return Err(Error::MilestonesNotConfigured);
         }
-        milestones
+        Ok(milestones)
     }
 
     fn unlocked_percentage(milestones: &Vec<Milestone>) -> u32 {
@@ -291,41 +871,258 @@ impl VestingContract {
     }
 
     // Propose a new admin (first step of two-step process)
-    pub fn propose_new_admin(env: Env, new_admin: Address) {
-        Self::require_admin(&env);
+    pub fn propose_new_admin(env: Env, new_admin: Address) -> Result<(), Error> {
+        Self::require_admin(&env)?;
         env.storage()
             .instance()
             .set(&DataKey::ProposedAdmin, &new_admin);
+
+        Ok(())
     }
 
     // Accept admin ownership (second step of two-step process)
-    pub fn accept_ownership(env: Env) {
+    pub fn accept_ownership(env: Env) -> Result<(), Error> {
         let proposed_admin: Address = env
             .storage()
             .instance()
             .get(&DataKey::ProposedAdmin)
-            .unwrap_or_else(|| panic!("No proposed admin found"));
+            .ok_or(Error::NoProposedAdmin)?;
         proposed_admin.require_auth();
         env.storage()
             .instance()
             .set(&DataKey::AdminAddress, &proposed_admin);
         env.storage().instance().remove(&DataKey::ProposedAdmin);
+
+        Ok(())
+    }
+
+    // Admin-only: replace the contract code with an uploaded WASM. Stored records are
+    // migrated lazily by the new code; call `migrate` afterwards to bump the schema version.
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), Error> {
+        Self::require_admin(&env)?;
+
+        env.storage()
+            .instance()
+            .set(&DataKey::WasmHash, &new_wasm_hash);
+
+        Self::record_admin_action(
+            &env,
+            AdminAction::ContractUpgraded,
+            None,
+            vec![&env, new_wasm_hash.clone().into_val(&env)],
+        )?;
+
+        ContractUpgraded {
+            new_wasm_hash: new_wasm_hash.clone(),
+            schema_version: Self::get_schema_version(env.clone()),
+            version: EVENT_VERSION,
+        }
+        .publish(&env);
+
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+
+        Ok(())
+    }
+
+    // Admin-only: record that storage now follows SCHEMA_VERSION. Per-vault records are
+    // still converted on first access, so this never iterates vaults. Schema 1 did not
+    // keep every accounting total, so migrating from it zeroes them and leaves the
+    // existing vaults to be counted by `recount_totals`.
+    pub fn migrate(env: Env) -> Result<u32, Error> {
+        Self::require_admin(&env)?;
+
+        let from = Self::get_schema_version(env.clone());
+        if from >= SCHEMA_VERSION {
+            return Err(Error::SchemaUpToDate);
+        }
+
+        if from == 1 {
+            let storage = env.storage().instance();
+            for key in [
+                DataKey::TotalShares,
+                DataKey::TotalReleased,
+                DataKey::TotalRevoked,
+                DataKey::TotalStaked,
+            ] {
+                storage.set(&key, &0i128);
+            }
+            let vault_count: u64 = storage.get(&DataKey::VaultCount).unwrap_or(0);
+            if vault_count > 0 {
+                storage.set(
+                    &DataKey::TotalsRecount,
+                    &TotalsRecount {
+                        next_vault_id: 1,
+                        last_vault_id: vault_count,
+                    },
+                );
+            }
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::SchemaVersion, &SCHEMA_VERSION);
+        Self::extend_instance_ttl(&env);
+
+        SchemaMigrated {
+            from_version: from,
+            to_version: SCHEMA_VERSION,
+            version: EVENT_VERSION,
+        }
+        .publish(&env);
+
+        Ok(SCHEMA_VERSION)
+    }
+
+    // Admin-only: add up to `limit` vaults left over by `migrate` to the accounting
+    // totals. Vaults created since the migration are already counted. Records do not
+    // tell claims from revocations, so earlier revocations are counted as released.
+    // Returns the vault ID the next call resumes from, or None once every vault is in.
+    pub fn recount_totals(env: Env, limit: u32) -> Result<Option<u64>, Error> {
+        Self::require_admin(&env)?;
+
+        let mut recount: TotalsRecount = match env.storage().instance().get(&DataKey::TotalsRecount)
+        {
+            Some(recount) => recount,
+            None => return Ok(None),
+        };
+
+        let end = recount
+            .next_vault_id
+            .saturating_add(limit.min(MAX_PAGE_SIZE) as u64)
+            .min(recount.last_vault_id + 1);
+        let (mut locked, mut released, mut staked) = (0i128, 0i128, 0i128);
+        for vault_id in recount.next_vault_id..end {
+            if let Some(vault) = Self::try_load_vault(&env, vault_id)? {
+                locked += vault.total_amount - vault.released_amount;
+                released += vault.released_amount;
+                staked += vault.staked_amount;
+            } else if let Some(archived) = env
+                .storage()
+                .persistent()
+                .get::<_, ArchivedVault>(&DataKey::ArchivedVault(vault_id))
+            {
+                released += archived.total_amount;
+            }
+        }
+        Self::add_to_total(&env, DataKey::TotalShares, locked);
+        Self::add_to_total(&env, DataKey::TotalReleased, released);
+        Self::add_to_total(&env, DataKey::TotalStaked, staked);
+
+        if end > recount.last_vault_id {
+            env.storage().instance().remove(&DataKey::TotalsRecount);
+            return Ok(None);
+        }
+        recount.next_vault_id = end;
+        env.storage()
+            .instance()
+            .set(&DataKey::TotalsRecount, &recount);
+        Ok(Some(end))
+    }
+
+    // Vaults still waiting for `recount_totals`, or None when the totals are complete
+    pub fn get_totals_recount(env: Env) -> Option<TotalsRecount> {
+        env.storage().instance().get(&DataKey::TotalsRecount)
+    }
+
+    // Deployments initialized before versioning was introduced report schema 1
+    pub fn get_schema_version(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::SchemaVersion)
+            .unwrap_or(1)
+    }
+
+    pub fn get_wasm_hash(env: Env) -> Option<BytesN<32>> {
+        env.storage().instance().get(&DataKey::WasmHash)
+    }
+
+    fn load_vault(env: &Env, vault_id: u64) -> Result<Vault, Error> {
+        Self::try_load_vault(env, vault_id)?.ok_or(Error::VaultNotFound)
+    }
+
+    // Read a vault record, converting and rewriting older layouts in place.
+    // Schema 1 deployments kept vaults and their milestones in instance
+    // storage; those are moved to persistent storage on first access.
+    fn try_load_vault(env: &Env, vault_id: u64) -> Result<Option<Vault>, Error> {
+        let key = DataKey::VaultData(vault_id);
+        let in_instance =
+            !env.storage().persistent().has(&key) && env.storage().instance().has(&key);
+        let raw: Map<Symbol, Val> = match Self::get_persistent(env, &key) {
+            Some(raw) => raw,
+            None => return Ok(None),
+        };
+        if in_instance {
+            Self::get_persistent::<Vec<Milestone>>(env, &DataKey::VaultMilestones(vault_id));
+        }
+        // Decoding a struct from a map with a different field count traps in the host,
+        // so only decode directly when the record has every current field
+        if raw.len() == VAULT_FIELD_COUNT {
+            let vault = Vault::try_from_val(env, &raw.to_val())
+                .map_err(|_| Error::UnrecognizedVaultLayout)?;
+            return Ok(Some(vault));
+        }
+
+        // Any older layout, including intermediate ones with only some of the
+        // newer fields: take what is there and default the rest
+        let vault = Vault {
+            total_amount: Self::vault_field(env, &raw, "total_amount", None)?,
+            released_amount: Self::vault_field(env, &raw, "released_amount", None)?,
+            keeper_fee: Self::vault_field(env, &raw, "keeper_fee", Some(0))?,
+            staked_amount: Self::vault_field(env, &raw, "staked_amount", Some(0))?,
+            owner: Self::vault_field(env, &raw, "owner", None)?,
+            delegate: Self::vault_field(env, &raw, "delegate", Some(None))?,
+            title: Self::vault_field(env, &raw, "title", Some(String::from_str(env, "")))?,
+            start_time: Self::vault_field(env, &raw, "start_time", None)?,
+            end_time: Self::vault_field(env, &raw, "end_time", None)?,
+            creation_time: Self::vault_field(env, &raw, "creation_time", Some(0))?,
+            step_duration: Self::vault_field(env, &raw, "step_duration", Some(0))?,
+            is_initialized: Self::vault_field(env, &raw, "is_initialized", Some(true))?,
+            is_irrevocable: Self::vault_field(env, &raw, "is_irrevocable", Some(false))?,
+            is_transferable: Self::vault_field(env, &raw, "is_transferable", Some(false))?,
+            is_frozen: Self::vault_field(env, &raw, "is_frozen", Some(false))?,
+        };
+        Self::set_persistent(env, &key, &vault);
+        VaultMigrated {
+            vault_id,
+            from_version: 1,
+            to_version: SCHEMA_VERSION,
+            version: EVENT_VERSION,
+        }
+        .publish(env);
+
+        Ok(Some(vault))
+    }
+
+    // One field of an older vault record, or `default` if that layout lacked it
+    fn vault_field<T: TryFromVal<Env, Val>>(
+        env: &Env,
+        raw: &Map<Symbol, Val>,
+        name: &str,
+        default: Option<T>,
+    ) -> Result<T, Error> {
+        match raw.get(Symbol::new(env, name)) {
+            Some(val) => T::try_from_val(env, &val).map_err(|_| Error::UnrecognizedVaultLayout),
+            None => default.ok_or(Error::UnrecognizedVaultLayout),
+        }
     }
 
     // Emergency migration: freeze contract and transfer all whitelisted token balances to V2.
     // Admin-only. Sets `is_deprecated = true`.
-    pub fn migrate_liquidity(env: Env, v2_contract_address: Address) -> Map<Address, i128> {
-        Self::require_admin(&env);
+    pub fn migrate_liquidity(
+        env: Env,
+        v2_contract_address: Address,
+    ) -> Result<Map<Address, i128>, Error> {
+        Self::require_admin(&env)?;
 
         if v2_contract_address == env.current_contract_address() {
-            panic!("v2_contract_address must differ from current contract");
+            return Err(Error::InvalidMigrationTarget);
         }
 
-        proposed_admin.require_auth();
-
+        env.storage().instance().set(&DataKey::IsDeprecated, &true);
+        env.storage().instance().set(&DataKey::IsPaused, &true);
+        env.storage().instance().remove(&DataKey::PauseExpiry);
         env.storage()
             .instance()
-            .set(&DataKey::AdminAddress, &proposed_admin);
             .set(&DataKey::MigrationTarget, &v2_contract_address);
 
         let whitelist: Map<Address, bool> = env
@@ -350,29 +1147,45 @@ impl VestingContract {
             }
 
             migrated.set(token_address.clone(), balance);
-            env.events().publish(
-                (Symbol::new(&env, "LiquidityMigrated"), token_address),
-                (v2_contract_address.clone(), balance),
-            );
+            LiquidityMigrated {
+                token: token_address,
+                target: v2_contract_address.clone(),
+                amount: balance,
+                version: EVENT_VERSION,
+            }
+            .publish(&env);
         }
 
         let timestamp = env.ledger().timestamp();
-        env.events()
-            .publish((Symbol::new(&env, "ContractDeprecated"),), (v2_contract_address, timestamp));
-        env.events().publish(
-            (Symbol::new(&env, "ContractDeprecated"),),
-            v2_contract_address,
-        );
-
-        env.storage().instance().remove(&DataKey::ProposedAdmin);
+        ContractDeprecated {
+            target: v2_contract_address,
+            timestamp,
+            version: EVENT_VERSION,
+        }
+        .publish(&env);
+
+        Ok(migrated)
+    }
+
+    // Check whether the contract has been deprecated by `migrate_liquidity`
+    pub fn is_deprecated(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::IsDeprecated)
+            .unwrap_or(false)
+    }
+
+    // Get the V2 contract address liquidity was migrated to (if any)
+    pub fn get_migration_target(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::MigrationTarget)
     }
 
     // Get current admin address
-    pub fn get_admin(env: Env) -> Address {
+    pub fn get_admin(env: Env) -> Result<Address, Error> {
         env.storage()
             .instance()
             .get(&DataKey::AdminAddress)
-            .unwrap_or_else(|| panic!("Admin not set"))
+            .ok_or(Error::AdminNotSet)
     }
 
     // Get proposed admin address (if any)
@@ -380,95 +1193,519 @@ impl VestingContract {
         env.storage().instance().get(&DataKey::ProposedAdmin)
     }
 
-    // Toggle pause state (Admin only) - "Big Red Button" for emergency pause
-    pub fn toggle_pause(env: Env) {
-        Self::require_admin(&env);
+    // Toggle pause state (Admin only) - "Big Red Button" for emergency pause.
+    // A pause set here has no expiry; use `pause_with_expiry` for a time-boxed one.
+    pub fn toggle_pause(env: Env) -> Result<(), Error> {
+        Self::require_admin(&env)?;
 
-        let current_pause_state: bool = env
-            .storage()
-            .instance()
-            .get(&DataKey::IsPaused)
-            .unwrap_or(false);
+        let current_pause_state = Self::is_paused(env.clone());
 
         let new_pause_state = !current_pause_state;
         env.storage()
             .instance()
             .set(&DataKey::IsPaused, &new_pause_state);
+        env.storage().instance().remove(&DataKey::PauseExpiry);
 
         // Emit event for pause state change
-        env.events().publish(
-            (Symbol::new(&env, "PauseToggled"),),
-            (new_pause_state, env.ledger().timestamp()),
-        );
+        PauseToggled {
+            paused: new_pause_state,
+            timestamp: env.ledger().timestamp(),
+            version: EVENT_VERSION,
+        }
+        .publish(&env);
+
+        Ok(())
     }
 
-    // Get current pause state
-    pub fn is_paused(env: Env) -> bool {
+    // Pause withdrawals for at most `duration` seconds (Admin only).
+    // Claims resume automatically once the pause lapses; calling again renews
+    // the pause from the current ledger time.
+    pub fn pause_with_expiry(env: Env, duration: u64) -> Result<u64, Error> {
+        Self::require_admin(&env)?;
+
+        if duration == 0 {
+            return Err(Error::InvalidPauseDuration);
+        }
+        if duration > MAX_PAUSE_DURATION {
+            return Err(Error::PauseDurationTooLong);
+        }
+
+        let now = env.ledger().timestamp();
+        let expires_at = now + duration;
+        env.storage().instance().set(&DataKey::IsPaused, &true);
         env.storage()
+            .instance()
+            .set(&DataKey::PauseExpiry, &expires_at);
+
+        PauseScheduled {
+            expires_at,
+            timestamp: now,
+            version: EVENT_VERSION,
+        }
+        .publish(&env);
+
+        Ok(expires_at)
+    }
+
+    // Get current pause state (a lapsed time-boxed pause reads as unpaused)
+    pub fn is_paused(env: Env) -> bool {
+        let paused: bool = env
+            .storage()
             .instance()
             .get(&DataKey::IsPaused)
-            .unwrap_or(false)
+            .unwrap_or(false);
+        if !paused {
+            return false;
+        }
+        match env
+            .storage()
+            .instance()
+            .get::<_, u64>(&DataKey::PauseExpiry)
+        {
+            Some(expires_at) => env.ledger().timestamp() < expires_at,
+            None => true,
+        }
+    }
+
+    // Get the timestamp at which the current pause lapses (None if unpaused or indefinite)
+    pub fn get_pause_expiry(env: Env) -> Option<u64> {
+        if !Self::is_paused(env.clone()) {
+            return None;
+        }
+        env.storage().instance().get(&DataKey::PauseExpiry)
     }
 
-    // Freeze a specific vault (Admin only) - prevents claims on this vault
-    pub fn freeze_vault(env: Env, vault_id: u64) {
-        Self::require_admin(&env);
+    // Pause or resume a single flow (Admin only) without touching the others
+    pub fn set_scope_paused(env: Env, scope: PauseScope, paused: bool) -> Result<(), Error> {
+        Self::require_admin(&env)?;
 
-        let mut vault: Vault = env
-            .storage()
+        env.storage()
             .instance()
-            .get(&DataKey::VaultData(vault_id))
-            .unwrap_or_else(|| panic!("Vault not found"));
+            .set(&DataKey::ScopePaused(scope), &paused);
 
-        if vault.is_frozen {
-            panic!("Vault is already frozen");
+        ScopePauseSet {
+            scope,
+            paused,
+            timestamp: env.ledger().timestamp(),
+            version: EVENT_VERSION,
         }
+        .publish(&env);
 
-        vault.is_frozen = true;
+        Ok(())
+    }
+
+    // Check whether a flow is currently halted, either by its own flag or,
+    // for withdrawal flows, by the global pause
+    pub fn is_scope_paused(env: Env, scope: PauseScope) -> bool {
+        let withdrawal_flow = matches!(scope, PauseScope::Claims | PauseScope::Keeper);
+        if withdrawal_flow && Self::is_paused(env.clone()) {
+            return true;
+        }
         env.storage()
             .instance()
-            .set(&DataKey::VaultData(vault_id), &vault);
+            .get(&DataKey::ScopePaused(scope))
+            .unwrap_or(false)
+    }
 
-        env.events().publish(
-            (Symbol::new(&env, "VaultFrozen"), vault_id),
-            env.ledger().timestamp(),
-        );
+    // Get the effective pause state of every scope
+    pub fn get_pause_matrix(env: Env) -> PauseMatrix {
+        PauseMatrix {
+            global: Self::is_paused(env.clone()),
+            claims: Self::is_scope_paused(env.clone(), PauseScope::Claims),
+            vault_creation: Self::is_scope_paused(env.clone(), PauseScope::VaultCreation),
+            staking: Self::is_scope_paused(env.clone(), PauseScope::Staking),
+            transfers: Self::is_scope_paused(env.clone(), PauseScope::Transfers),
+            keeper: Self::is_scope_paused(env, PauseScope::Keeper),
+        }
+    }
+
+    fn require_scope_active(env: &Env, scope: PauseScope) -> Result<(), Error> {
+        if !Self::is_scope_paused(env.clone(), scope) {
+            return Ok(());
+        }
+        if Self::is_paused(env.clone()) {
+            return Err(Error::ContractPaused);
+        }
+        Err(match scope {
+            PauseScope::Claims => Error::ClaimsPaused,
+            PauseScope::VaultCreation => Error::VaultCreationPaused,
+            PauseScope::Staking => Error::StakingPaused,
+            PauseScope::Transfers => Error::TransfersPaused,
+            PauseScope::Keeper => Error::KeeperPaused,
+        })
+    }
+
+    // Freeze a specific vault (Admin only) - prevents claims on this vault.
+    // Recorded with reason code 0, no case reference and no expiry.
+    pub fn freeze_vault(env: Env, vault_id: u64) -> Result<(), Error> {
+        Self::freeze_vault_with_reason(env, vault_id, 0, None, None)
+    }
+
+    // Freeze a vault for an investigation (Admin only). The freeze records why,
+    // by whom and, optionally, when it lapses without an explicit unfreeze.
+    pub fn freeze_vault_with_reason(
+        env: Env,
+        vault_id: u64,
+        reason_code: u32,
+        reference_hash: Option<BytesN<32>>,
+        expires_at: Option<u64>,
+    ) -> Result<(), Error> {
+        Self::require_admin(&env)?;
+        Self::internal_freeze(&env, vault_id, reason_code, reference_hash, expires_at)
     }
 
-    // Unfreeze a specific vault (Admin only) - allows claims on this vault again
-    pub fn unfreeze_vault(env: Env, vault_id: u64) {
-        Self::require_admin(&env);
+    fn internal_freeze(
+        env: &Env,
+        vault_id: u64,
+        reason_code: u32,
+        reference_hash: Option<BytesN<32>>,
+        expires_at: Option<u64>,
+    ) -> Result<(), Error> {
+        let mut vault: Vault = Self::load_vault(env, vault_id)?;
+
+        if Self::is_frozen_now(env, vault_id, &vault) {
+            return Err(Error::VaultAlreadyFrozen);
+        }
+
+        let now = env.ledger().timestamp();
+        if let Some(expiry) = expires_at {
+            if expiry <= now {
+                return Err(Error::InvalidFreezeExpiry);
+            }
+        }
+
+        let record = FreezeRecord {
+            reason_code,
+            reference_hash: reference_hash.clone(),
+            frozen_by: Self::get_admin(env.clone())?,
+            frozen_at: now,
+            expires_at,
+        };
+        Self::set_persistent(env, &DataKey::VaultFreeze(vault_id), &record);
 
-        let mut vault: Vault = env
+        let mut frozen_vaults: Vec<u64> = env
             .storage()
-            .instance()
-            .get(&DataKey::VaultData(vault_id))
-            .unwrap_or_else(|| panic!("Vault not found"));
+            .persistent()
+            .get(&DataKey::FrozenVaults)
+            .unwrap_or(Vec::new(env));
+        if !frozen_vaults.contains(vault_id) {
+            frozen_vaults.push_back(vault_id);
+            Self::set_persistent(env, &DataKey::FrozenVaults, &frozen_vaults);
+        }
+
+        vault.is_frozen = true;
+        Self::set_persistent(env, &DataKey::VaultData(vault_id), &vault);
+
+        Self::record_admin_action(
+            env,
+            AdminAction::VaultFrozen,
+            Some(vault_id),
+            vec![env, reason_code.into_val(env), expires_at.into_val(env)],
+        )?;
+
+        VaultFrozen {
+            vault_id,
+            reason_code,
+            reference_hash,
+            frozen_by: record.frozen_by,
+            expires_at,
+            timestamp: now,
+            version: EVENT_VERSION,
+        }
+        .publish(env);
+
+        Ok(())
+    }
+
+    // Unfreeze a specific vault (Admin only) - allows claims on this vault again.
+    // A freeze that already lapsed is accepted too, which clears its leftover
+    // record and drops the vault from the frozen-vault index.
+    pub fn unfreeze_vault(env: Env, vault_id: u64) -> Result<(), Error> {
+        Self::require_admin(&env)?;
+
+        let mut vault: Vault = Self::load_vault(&env, vault_id)?;
 
         if !vault.is_frozen {
-            panic!("Vault is not frozen");
+            return Err(Error::VaultNotFrozen);
         }
 
         vault.is_frozen = false;
+        Self::set_persistent(&env, &DataKey::VaultData(vault_id), &vault);
+        Self::clear_freeze_record(&env, vault_id);
+
+        Self::record_admin_action(&env, AdminAction::VaultUnfrozen, Some(vault_id), vec![&env])?;
+
+        VaultUnfrozen {
+            vault_id,
+            timestamp: env.ledger().timestamp(),
+            version: EVENT_VERSION,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    // A freeze is in force while the flag is set and its expiry (if any) has not passed
+    fn is_frozen_now(env: &Env, vault_id: u64, vault: &Vault) -> bool {
+        Self::freeze_in_force(env, vault_id, vault.is_frozen)
+    }
+
+    fn freeze_in_force(env: &Env, vault_id: u64, flagged: bool) -> bool {
+        if !flagged {
+            return false;
+        }
+        match env
+            .storage()
+            .persistent()
+            .get::<_, FreezeRecord>(&DataKey::VaultFreeze(vault_id))
+            .and_then(|record| record.expires_at)
+        {
+            Some(expiry) => env.ledger().timestamp() < expiry,
+            None => true,
+        }
+    }
+
+    fn clear_freeze_record(env: &Env, vault_id: u64) {
+        env.storage()
+            .persistent()
+            .remove(&DataKey::VaultFreeze(vault_id));
+
+        let frozen_vaults: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::FrozenVaults)
+            .unwrap_or(Vec::new(env));
+        let mut remaining = Vec::new(env);
+        for id in frozen_vaults.iter() {
+            if id != vault_id {
+                remaining.push_back(id);
+            }
+        }
+        Self::set_persistent(env, &DataKey::FrozenVaults, &remaining);
+    }
+
+    // Check if a specific vault is frozen. Reads the stored record as is, so
+    // an older layout is not migrated by this view.
+    pub fn is_vault_frozen(env: Env, vault_id: u64) -> Result<bool, Error> {
+        let key = DataKey::VaultData(vault_id);
+        let raw: Map<Symbol, Val> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .or_else(|| env.storage().instance().get(&key))
+            .ok_or(Error::VaultNotFound)?;
+        let flagged = Self::vault_field(&env, &raw, "is_frozen", Some(false))?;
+
+        Ok(Self::freeze_in_force(&env, vault_id, flagged))
+    }
+
+    // Get the freeze details of a vault (None if the vault is not currently frozen)
+    pub fn get_freeze_record(env: Env, vault_id: u64) -> Result<Option<FreezeRecord>, Error> {
+        if !Self::is_vault_frozen(env.clone(), vault_id)? {
+            return Ok(None);
+        }
+        Ok(env
+            .storage()
+            .persistent()
+            .get(&DataKey::VaultFreeze(vault_id)))
+    }
+
+    // Page through the vaults whose freeze is currently in force, oldest
+    // freeze first. `cursor` is a position in the frozen-vault index; lapsed
+    // freezes keep their slot until `unfreeze_vault` clears them and are
+    // skipped. At most MAX_LIST_SCAN slots are inspected per call; pass
+    // `next_cursor` back to continue.
+    pub fn list_frozen_vaults(env: Env, cursor: u64, limit: u32) -> VaultPage {
+        let frozen_vaults: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::FrozenVaults)
+            .unwrap_or(Vec::new(&env));
+        let count = frozen_vaults.len() as u64;
+        let limit = limit.min(MAX_PAGE_SIZE);
+        let last_scannable = cursor.saturating_add(MAX_LIST_SCAN as u64).min(count);
+
+        let mut vault_ids = Vec::new(&env);
+        let mut position = cursor;
+        while position < last_scannable && vault_ids.len() < limit {
+            let vault_id = frozen_vaults.get(position as u32).unwrap();
+            position += 1;
+            if Self::is_vault_frozen(env.clone(), vault_id).unwrap_or(false) {
+                vault_ids.push_back(vault_id);
+            }
+        }
+
+        VaultPage {
+            vault_ids,
+            next_cursor: if position < count {
+                Some(position)
+            } else {
+                None
+            },
+        }
+    }
+
+    // Number of entries in the frozen-vault index, lapsed freezes included
+    pub fn get_frozen_vault_count(env: Env) -> u32 {
         env.storage()
+            .persistent()
+            .get::<_, Vec<u64>>(&DataKey::FrozenVaults)
+            .map(|ids| ids.len())
+            .unwrap_or(0)
+    }
+
+    // Balance changes on a vault `recount_totals` has not reached yet would be
+    // counted twice, so they wait until the recount has passed it
+    fn require_vault_counted(env: &Env, vault_id: u64) -> Result<(), Error> {
+        if let Some(recount) = env
+            .storage()
             .instance()
-            .set(&DataKey::VaultData(vault_id), &vault);
+            .get::<_, TotalsRecount>(&DataKey::TotalsRecount)
+        {
+            if vault_id >= recount.next_vault_id && vault_id <= recount.last_vault_id {
+                return Err(Error::MigrationPending);
+            }
+        }
+        Ok(())
+    }
 
-        env.events().publish(
-            (Symbol::new(&env, "VaultUnfrozen"), vault_id),
-            env.ledger().timestamp(),
-        );
+    // Adjust one of the running accounting totals kept in instance storage
+    fn add_to_total(env: &Env, key: DataKey, delta: i128) {
+        let total: i128 = env.storage().instance().get(&key).unwrap_or(0);
+        env.storage().instance().set(&key, &(total + delta));
     }
 
-    // Check if a specific vault is frozen
-    pub fn is_vault_frozen(env: Env, vault_id: u64) -> bool {
-        let vault: Vault = env
+    // Append an entry to the audit log. Entries live in persistent storage
+    // so the log can grow without bloating the instance entry; they start
+    // with the full TTL and are kept alive by `extend_audit_log_ttl` and
+    // `extend_vault_ttl`.
+    fn record_admin_action(
+        env: &Env,
+        action: AdminAction,
+        vault_id: Option<u64>,
+        params: Vec<Val>,
+    ) -> Result<(), Error> {
+        let id: u64 = env
             .storage()
             .instance()
-            .get(&DataKey::VaultData(vault_id))
-            .unwrap_or_else(|| panic!("Vault not found"));
+            .get(&DataKey::AuditLogCount)
+            .unwrap_or(0)
+            + 1;
+        let entry = AuditEntry {
+            id,
+            action,
+            actor: Self::get_admin(env.clone())?,
+            vault_id,
+            timestamp: env.ledger().timestamp(),
+            params,
+        };
+        Self::set_persistent(env, &DataKey::AuditEntry(id), &entry);
+        Self::extend_persistent(env, &DataKey::AuditEntry(id));
+        env.storage().instance().set(&DataKey::AuditLogCount, &id);
+
+        if let Some(vault_id) = vault_id {
+            let mut vault_log: Vec<u64> = env
+                .storage()
+                .persistent()
+                .get(&DataKey::VaultAuditLog(vault_id))
+                .unwrap_or(Vec::new(env));
+            vault_log.push_back(id);
+            Self::set_persistent(env, &DataKey::VaultAuditLog(vault_id), &vault_log);
+        }
+
+        Ok(())
+    }
+
+    // Number of entries in the global audit log
+    pub fn get_audit_log_count(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::AuditLogCount)
+            .unwrap_or(0)
+    }
+
+    // Extend a page of audit entries, oldest first, to the full TTL. Anyone may
+    // call this, as with `extend_vault_ttl`. Returns the `start_id` of the
+    // next page, or None once the end of the log is reached.
+    pub fn extend_audit_log_ttl(env: Env, start_id: u64, limit: u32) -> Option<u64> {
+        let count = Self::get_audit_log_count(env.clone());
+        let end = start_id
+            .max(1)
+            .saturating_add(limit.min(MAX_PAGE_SIZE) as u64)
+            .min(count + 1);
+        for id in start_id.max(1)..end {
+            let key = DataKey::AuditEntry(id);
+            if env.storage().persistent().has(&key) {
+                Self::extend_persistent(&env, &key);
+            }
+        }
+        Self::extend_instance_ttl(&env);
+        if end <= count {
+            Some(end)
+        } else {
+            None
+        }
+    }
+
+    // Page through the global audit log, oldest first. `start_id` is 1-based.
+    pub fn get_audit_log(env: Env, start_id: u64, limit: u32) -> Vec<AuditEntry> {
+        let count = Self::get_audit_log_count(env.clone());
+        let limit = limit.min(MAX_PAGE_SIZE) as u64;
+        let mut entries = Vec::new(&env);
+        let mut id = start_id.max(1);
+        while id <= count && (entries.len() as u64) < limit {
+            if let Some(entry) = env.storage().persistent().get(&DataKey::AuditEntry(id)) {
+                entries.push_back(entry);
+            }
+            id += 1;
+        }
+        entries
+    }
+
+    // Page through the audit entries for one vault, oldest first
+    pub fn get_vault_audit_log(
+        env: Env,
+        vault_id: u64,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<AuditEntry> {
+        let vault_log: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::VaultAuditLog(vault_id))
+            .unwrap_or(Vec::new(&env));
+        let end = vault_log
+            .len()
+            .min(offset.saturating_add(limit.min(MAX_PAGE_SIZE)));
+        let mut entries = Vec::new(&env);
+        for i in offset..end {
+            let id = vault_log.get(i).unwrap();
+            if let Some(entry) = env.storage().persistent().get(&DataKey::AuditEntry(id)) {
+                entries.push_back(entry);
+            }
+        }
+        entries
+    }
 
-        vault.is_frozen
+    // Publish `VaultCreated` with the vault's full schedule and flags as stored
+    fn emit_vault_created(env: &Env, vault_id: u64, vault: &Vault) {
+        VaultCreated {
+            vault_id,
+            owner: vault.owner.clone(),
+            total_amount: vault.total_amount,
+            keeper_fee: vault.keeper_fee,
+            title: vault.title.clone(),
+            start_time: vault.start_time,
+            end_time: vault.end_time,
+            cliff_duration: vault.start_time.saturating_sub(vault.creation_time),
+            step_duration: vault.step_duration,
+            creation_time: vault.creation_time,
+            is_initialized: vault.is_initialized,
+            is_irrevocable: vault.is_irrevocable,
+            is_transferable: vault.is_transferable,
+            version: EVENT_VERSION,
+        }
+        .publish(env);
     }
 
     // Full initialization - writes all metadata immediately
@@ -482,10 +1719,10 @@ impl VestingContract {
         is_revocable: bool,
         is_transferable: bool,
         step_duration: u64,
-    ) -> u64 {
-
-        Self::require_admin(&env);
-        Self::require_valid_duration(start_time, end_time);
+    ) -> Result<u64, Error> {
+        Self::require_admin(&env)?;
+        Self::require_scope_active(&env, PauseScope::VaultCreation)?;
+        Self::require_valid_duration(start_time, end_time)?;
 
         let mut vault_count: u64 = env
             .storage()
@@ -500,7 +1737,7 @@ impl VestingContract {
             .get(&DataKey::AdminBalance)
             .unwrap_or(0);
         if admin_balance < amount {
-            panic!("Insufficient admin balance");
+            return Err(Error::InsufficientAdminBalance);
         }
         admin_balance -= amount;
         env.storage()
@@ -509,45 +1746,33 @@ impl VestingContract {
 
         let now = env.ledger().timestamp();
 
-        let is_irrevocable = !is_revocable;
-
         let vault = Vault {
-            title: String::from_slice(&env, ""),
-            owner: owner.clone(),
-            delegate: None,
             total_amount: amount,
             released_amount: 0,
+            keeper_fee,
+            staked_amount: 0,
+            owner: owner.clone(),
+            delegate: None,
+            title: String::from_str(&env, ""),
             start_time,
             end_time,
-            keeper_fee,
-            title: String::from_slice(&env, ""),
-            is_initialized: true,
-            is_irrevocable,
             creation_time: now,
-            is_transferable,
             step_duration,
-            staked_amount: 0,
+            is_initialized: true,
+            is_irrevocable: !is_revocable,
+            is_transferable,
             is_frozen: false,
-            title: String::from_slice(&env, ""),
         };
 
-        env.storage()
-            .instance()
-            .set(&DataKey::VaultData(vault_count), &vault);
+        Self::set_persistent(&env, &DataKey::VaultData(vault_count), &vault);
 
-        let mut user_vaults: Vec<u64> = env
-            .storage()
-            .instance()
-            .get(&DataKey::UserVaults(owner.clone()))
-            .unwrap_or(Vec::new(&env));
+        let mut user_vaults: Vec<u64> =
+            Self::get_persistent(&env, &DataKey::UserVaults(owner.clone()))
+                .unwrap_or(Vec::new(&env));
         user_vaults.push_back(vault_count);
-        env.storage()
-            .instance()
-            .set(&DataKey::UserVaults(owner.clone()), &user_vaults);
+        Self::set_persistent(&env, &DataKey::UserVaults(owner.clone()), &user_vaults);
 
-        env.storage()
-            .instance()
-            .set(&DataKey::VaultCount, &vault_count);
+        Self::extend_vault_entries(&env, vault_count, &vault);
 
         env.storage()
             .instance()
@@ -563,21 +1788,22 @@ impl VestingContract {
             .instance()
             .set(&DataKey::TotalShares, &total_shares);
 
-        let cliff_duration = start_time.saturating_sub(now);
-        let vault_created = VaultCreated {
-            vault_id: vault_count,
-            beneficiary: owner,
-            total_amount: amount,
-            cliff_duration,
-            start_time,
-            title: String::from_slice(&env, ""),
-        };
-        env.events().publish(
-            (Symbol::new(&env, "VaultCreated"), vault_count),
-            vault_created,
-        );
+        Self::emit_vault_created(&env, vault_count, &vault);
 
-        vault_count
+        Self::record_admin_action(
+            &env,
+            AdminAction::VaultCreated,
+            Some(vault_count),
+            vec![
+                &env,
+                owner.into_val(&env),
+                amount.into_val(&env),
+                start_time.into_val(&env),
+                end_time.into_val(&env),
+            ],
+        )?;
+
+        Ok(vault_count)
     }
 
     // Lazy initialization - writes minimal data initially
@@ -591,9 +1817,10 @@ impl VestingContract {
         is_revocable: bool,
         is_transferable: bool,
         step_duration: u64,
-    ) -> u64 {
-        Self::require_admin(&env);
-        Self::require_valid_duration(start_time, end_time);
+    ) -> Result<u64, Error> {
+        Self::require_admin(&env)?;
+        Self::require_scope_active(&env, PauseScope::VaultCreation)?;
+        Self::require_valid_duration(start_time, end_time)?;
 
         let mut vault_count: u64 = env
             .storage()
@@ -608,7 +1835,7 @@ impl VestingContract {
             .get(&DataKey::AdminBalance)
             .unwrap_or(0);
         if admin_balance < amount {
-            panic!("Insufficient admin balance");
+            return Err(Error::InsufficientAdminBalance);
         }
         admin_balance -= amount;
         env.storage()
@@ -617,41 +1844,32 @@ impl VestingContract {
 
         let now = env.ledger().timestamp();
 
-        let is_irrevocable = !is_revocable;
-
         let vault = Vault {
-            title: String::from_slice(&env, ""),
-            owner: owner.clone(),
-            delegate: None,
             total_amount: amount,
             released_amount: 0,
+            keeper_fee,
+            staked_amount: 0,
+            owner: owner.clone(),
+            delegate: None,
+            title: String::from_str(&env, ""),
             start_time,
             end_time,
-            keeper_fee,
-            is_initialized: false,
-            is_irrevocable,
-            title: String::from_slice(&env, ""),
+            creation_time: now,
+            step_duration,
             is_initialized: false, // Mark as lazy initialized
             is_irrevocable: !is_revocable,
-            creation_time: now,
             is_transferable,
-            step_duration,
-            staked_amount: 0,
             is_frozen: false,
-            title: String::from_slice(&env, ""),
         };
 
-        env.storage()
-            .instance()
-            .set(&DataKey::VaultData(vault_count), &vault);
+        Self::set_persistent(&env, &DataKey::VaultData(vault_count), &vault);
+
+        Self::extend_vault_entries(&env, vault_count, &vault);
 
         // Don't update user vaults list yet (lazy)
         env.storage()
             .instance()
             .set(&DataKey::VaultCount, &vault_count);
-        env.storage()
-            .instance()
-            .set(&DataKey::VaultCount, &vault_count);
 
         let mut total_shares: i128 = env
             .storage()
@@ -663,125 +1881,77 @@ impl VestingContract {
             .instance()
             .set(&DataKey::TotalShares, &total_shares);
 
-        let cliff_duration = start_time.saturating_sub(now);
-        let vault_created = VaultCreated {
-            vault_id: vault_count,
-            beneficiary: owner.clone(),
-            total_amount: amount,
-            cliff_duration,
-            start_time,
-            title: String::from_slice(&env, ""),
-        };
-        env.events().publish(
-            (Symbol::new(&env, "VaultCreated"), vault_count),
-            vault_created,
-        );
+        Self::emit_vault_created(&env, vault_count, &vault);
 
-        vault_count
+        Self::record_admin_action(
+            &env,
+            AdminAction::VaultCreated,
+            Some(vault_count),
+            vec![
+                &env,
+                owner.into_val(&env),
+                amount.into_val(&env),
+                start_time.into_val(&env),
+                end_time.into_val(&env),
+            ],
+        )?;
+
+        Ok(vault_count)
     }
 
     // Initialize vault metadata when needed (on-demand)
-    fn initialize_vault_metadata(env: &Env, vault_id: u64) -> bool {
+    fn initialize_vault_metadata(env: &Env, vault_id: u64) -> Result<bool, Error> {
         if env
             .storage()
             .instance()
             .get(&DataKey::IsDeprecated)
             .unwrap_or(false)
         {
-            return false;
+            return Ok(false);
         }
 
-        let vault: Vault = env
-            .storage()
-            .instance()
-            .get(&DataKey::VaultData(vault_id))
-            .unwrap_or_else(|| panic!("Vault not found"));
+        let vault: Vault = Self::load_vault(env, vault_id)?;
 
         if !vault.is_initialized {
             let mut updated_vault = vault.clone();
             updated_vault.is_initialized = true;
 
-            env.storage()
-                .instance()
-                .set(&DataKey::VaultData(vault_id), &updated_vault);
+            Self::set_persistent(env, &DataKey::VaultData(vault_id), &updated_vault);
 
-            let mut user_vaults: Vec<u64> = env
-                .storage()
-                .instance()
-                .get(&DataKey::UserVaults(updated_vault.owner.clone()))
-                .unwrap_or(Vec::new(env));
+            let mut user_vaults: Vec<u64> =
+                Self::get_persistent(env, &DataKey::UserVaults(updated_vault.owner.clone()))
+                    .unwrap_or(Vec::new(env));
             user_vaults.push_back(vault_id);
-            env.storage()
-                .instance()
-                .set(&DataKey::UserVaults(updated_vault.owner), &user_vaults);
+            Self::set_persistent(
+                env,
+                &DataKey::UserVaults(updated_vault.owner.clone()),
+                &user_vaults,
+            );
 
-            true
+            VaultInitialized {
+                vault_id,
+                owner: updated_vault.owner,
+                version: EVENT_VERSION,
+            }
+            .publish(env);
+
+            Ok(true)
         } else {
-            false // Already initialized
+            Ok(false) // Already initialized
         }
     }
 
-    /// Helper functions for common time durations in seconds
-/// These can be used when creating vaults with periodic vesting
-impl VestingContract {
-    /// Convert days to seconds
-    pub const fn seconds(days: u64) -> u64 { days * 86400 }
-    
-    /// 30 days in seconds (monthly vesting)
-    pub const fn monthly() -> u64 { 30 * 86400 } // 2,592,000 seconds
-    
-    /// 90 days in seconds (quarterly vesting)  
-    pub const fn quarterly() -> u64 { 3 * 30 * 86400 } // 7,776,000 seconds
-    
-    /// 365 days in seconds (yearly vesting)
-    pub const fn yearly() -> u64 { 365 * 86400 } // 31,536,000 seconds
-}
-
-/// Calculate the amount of tokens that have vested based on time
-/// 
-/// Supports two vesting modes:
-/// 1. Linear vesting (step_duration = 0): Continuous vesting over time
-/// 2. Periodic vesting (step_duration > 0): Discrete step vesting with rounding down
-/// 
-/// For periodic vesting, the formula is:
-/// vested = (elapsed / step_duration) * (total_amount / total_steps) * step_duration
-/// This rounds down to the nearest completed step, ensuring users only receive
-/// tokens that have fully vested according to the step schedule.
-/// 
-/// # Arguments
-/// * `env` - The contract environment
-/// * `vault` - The vault to calculate vesting for
-/// 
-/// # Returns
-/// The amount of tokens that have vested
-fn calculate_time_vested_amount(env: &Env, vault: &Vault) -> i128 {
-        let now = env.ledger().timestamp();
-        if now < vault.start_time { return 0; }
-        if now >= vault.end_time { return vault.total_amount; }
-        
-        let duration = vault.end_time - vault.start_time;
-        if duration == 0 { return vault.total_amount; }
-        
-        let elapsed = now - vault.start_time;
-
-        if vault.step_duration > 0 {
-            // Periodic vesting with monthly rounding
-            // First, round elapsed time down to the nearest step boundary
-            let rounded_elapsed = (elapsed / vault.step_duration) * vault.step_duration;
-            
-            // Calculate completed steps
-            let completed_steps = rounded_elapsed / vault.step_duration;
-            
-            // Calculate rate per step: total_amount / total_steps
-            let total_steps = duration / vault.step_duration;
-            if total_steps == 0 { return 0; }
-            
-            let amount_per_step = vault.total_amount / total_steps as i128;
-            let vested = completed_steps as i128 * amount_per_step;
-    // Helper to calculate vested amount based on time (linear or step)
+    /// Calculate the amount of tokens that have vested based on time
+    ///
+    /// Supports two vesting modes:
+    /// 1. Linear vesting (step_duration = 0): Continuous vesting over time
+    /// 2. Periodic vesting (step_duration > 0): Discrete step vesting with rounding down
+    ///
+    /// For periodic vesting, elapsed time is rounded down to the nearest completed
+    /// step before applying the linear formula, so users only receive tokens that
+    /// have fully vested according to the step schedule.
     fn calculate_time_vested_amount(env: &Env, vault: &Vault) -> i128 {
         let now = env.ledger().timestamp();
-        if now < vault.start_time {
         if now <= vault.start_time {
             return 0;
         }
@@ -795,186 +1965,495 @@ fn calculate_time_vested_amount(env: &Env, vault: &Vault) -> i128 {
         }
 
         let elapsed = now - vault.start_time;
-        let effective_elapsed = if vault.step_duration > 0 {
-            (elapsed / vault.step_duration) * vault.step_duration
-        } else {
-            elapsed
+        let effective_elapsed = match elapsed.checked_div(vault.step_duration) {
+            Some(completed_steps) => completed_steps * vault.step_duration,
+            None => elapsed, // step_duration == 0: linear vesting
         };
 
-        if vault.step_duration > 0 {
-            let completed_steps = elapsed / vault.step_duration;
-            let rate_per_second = vault.total_amount / duration as i128;
-            let vested = completed_steps as i128 * rate_per_second * vault.step_duration as i128;
-            
-            // Ensure we don't exceed total amount
-            vested.min(vault.total_amount)
+        (vault.total_amount * effective_elapsed as i128) / duration as i128
+    }
+
+    // Amount of a vault unlocked so far: milestone-gated when milestones are
+    // configured, time-vested otherwise
+    fn unlocked_for(env: &Env, vault_id: u64, vault: &Vault) -> Result<i128, Error> {
+        if Self::get_persistent::<Vec<Milestone>>(env, &DataKey::VaultMilestones(vault_id))
+            .is_some()
+        {
+            let milestones = Self::require_milestones_configured(env, vault_id)?;
+            let unlocked_pct = Self::unlocked_percentage(&milestones);
+            Ok(Self::unlocked_amount(vault.total_amount, unlocked_pct))
         } else {
-            // Linear vesting
-            (vault.total_amount * elapsed as i128) / duration as i128
+            Ok(Self::calculate_time_vested_amount(env, vault))
         }
     }
 
-    // Keep original signature for existing logic to use current ledger time
-    fn calculate_time_vested_amount(env: &Env, vault: &Vault) -> i128 {
-        Self::calculate_time_vested_amount_at(vault, env.ledger().timestamp())
-        (vault.total_amount * effective_elapsed as i128) / duration as i128
-    }
-
-    // Claim tokens from vault
-    pub fn claim_tokens(env: Env, vault_id: u64, claim_amount: i128) -> i128 {
-        if Self::is_paused(env.clone()) {
-            panic!("Contract is paused");
-        // Check if contract is paused
-        if Self::is_paused(env.clone()) {
-            panic!("Contract is paused - all withdrawals are disabled");
+    // Single claim pipeline behind claim_tokens, claim_as_delegate,
+    // claim_and_call, auto_claim and claim_with_signature. Applies the shared
+    // guards, authorizes the caller for `path`, unstakes any shortfall,
+    // converts shares to tokens (yield included), updates accounting, pays
+    // `recipient` (and the keeper or relayer fee, if any) and emits the claim
+    // events.
+    //
+    // `claim_amount = None` claims everything currently unlocked. When
+    // `deferred` is given the recipient transfer is left to the caller, which
+    // batches several claims into one: the tokens owed are added to it, and
+    // tokens already owed count as paid out when converting shares.
+    // `deferred_fee` does the same for the keeper fee transfer.
+    fn execute_claim(
+        env: &Env,
+        vault_id: u64,
+        claim_amount: Option<i128>,
+        path: ClaimPath,
+        recipient: Option<Address>,
+        deferred: Option<&mut i128>,
+        deferred_fee: Option<&mut i128>,
+    ) -> Result<ClaimOutcome, Error> {
+        Self::require_not_deprecated(env)?;
+        Self::require_scope_active(env, PauseScope::Claims)?;
+        if let ClaimPath::Keeper(_) = path {
+            Self::require_scope_active(env, PauseScope::Keeper)?;
         }
+        Self::require_vault_counted(env, vault_id)?;
 
-        let mut vault: Vault = env
-            .storage()
-            .instance()
-            .get(&DataKey::VaultData(vault_id))
-            .unwrap_or_else(|| panic!("Vault not found"));
-
-        // Check if vault is frozen
-        if vault.is_frozen {
-            panic!("Vault is frozen - claims are disabled");
+        let mut vault: Vault = Self::load_vault(env, vault_id)?;
+        if Self::is_frozen_now(env, vault_id, &vault) {
+            return Err(Error::VaultFrozen);
         }
-
         if !vault.is_initialized {
-            panic!("Vault not initialized");
+            return Err(Error::VaultNotInitialized);
         }
-        if claim_amount <= 0 {
-            panic!("Claim amount must be positive");
+        if let Some(amount) = claim_amount {
+            if amount <= 0 {
+                return Err(Error::InvalidAmount);
+            }
         }
 
-        let unlocked_amount =
-            if env.storage().instance().has(&DataKey::VaultMilestones(vault_id)) {
-                let milestones = Self::require_milestones_configured(&env, vault_id);
-                let unlocked_pct = Self::unlocked_percentage(&milestones);
-                Self::unlocked_amount(vault.total_amount, unlocked_pct)
-            } else {
-                Self::calculate_time_vested_amount(&env, &vault)
-            };
-
-        vault.owner.require_auth();
+        let claimant = match &path {
+            ClaimPath::Owner => {
+                vault.owner.require_auth();
+                vault.owner.clone()
+            }
+            ClaimPath::AuthorizedOwner(owner) => {
+                if vault.owner != *owner {
+                    vault.owner.require_auth();
+                }
+                vault.owner.clone()
+            }
+            ClaimPath::Delegate(delegate) => {
+                let delegate = Self::resolve_delegate(env, vault_id, &vault, delegate.clone())?;
+                delegate.require_auth();
+                Self::require_delegate_permitted(env, vault_id, &delegate, false)?;
+                delegate
+            }
+            ClaimPath::Keeper(keeper) => keeper.clone(),
+            // The owner's signature was checked by `claim_with_signature`
+            ClaimPath::Relayer(relayer, _) => {
+                relayer.require_auth();
+                relayer.clone()
+            }
+        };
+        let recipient = match recipient {
+            Some(recipient) => recipient,
+            None => Self::payout_address_for(env, &vault.owner),
+        };
 
-        let unlocked_amount = if env
+        let available = Self::unlocked_for(env, vault_id, &vault)? - vault.released_amount;
+        if available <= 0 {
+            return Err(Error::NothingToClaim);
+        }
+        let shares = match claim_amount {
+            Some(amount) if amount > available => return Err(Error::InsufficientUnlockedTokens),
+            Some(amount) => amount,
+            None => available,
+        };
+        match &path {
+            ClaimPath::Keeper(keeper) => {
+                Self::require_auto_claim_allowed(env, vault_id, keeper, shares)?
+            }
+            ClaimPath::Delegate(_) => {
+                Self::consume_delegate_allowance(env, vault_id, &claimant, shares)?
+            }
+            ClaimPath::Owner | ClaimPath::AuthorizedOwner(_) | ClaimPath::Relayer(..) => {}
+        }
+        // Sponsored vaults have the keeper fee paid from the reward pool
+        // instead of out of the claim
+        let sponsored = matches!(path, ClaimPath::Keeper(_))
+            && env
+                .storage()
+                .persistent()
+                .has(&DataKey::KeeperSponsored(vault_id));
+        let keeper_fee = match &path {
+            ClaimPath::Keeper(_) => Self::keeper_fee_for(env, vault_id, &vault, shares, sponsored)?,
+            ClaimPath::Relayer(_, fee) if *fee >= shares => {
+                return Err(Error::InsufficientClaimableForFee)
+            }
+            ClaimPath::Relayer(_, fee) => *fee,
+            _ => 0,
+        };
+        let pool: i128 = env
             .storage()
             .instance()
-            .has(&DataKey::VaultMilestones(vault_id))
-        {
-            let milestones = Self::require_milestones_configured(&env, vault_id);
-            let unlocked_pct = Self::unlocked_percentage(&milestones);
-            Self::unlocked_amount(vault.total_amount, unlocked_pct)
+            .get(&DataKey::KeeperPool)
+            .unwrap_or(0);
+        // Tokens already set aside by earlier claims in a batch
+        let owed = deferred.as_deref().copied().unwrap_or(0)
+            + deferred_fee.as_deref().copied().unwrap_or(0);
+        // The pool is held in tokens, so a sponsored fee is valued before it is checked
+        let sponsored_tokens = if sponsored {
+            Self::shares_to_tokens(env, keeper_fee, owed)?
         } else {
-            Self::calculate_time_vested_amount(&env, &vault)
+            0
         };
+        if sponsored_tokens > pool {
+            return Err(Error::KeeperPoolInsufficient);
+        }
 
+        // Pull back from staking whatever the vault's liquid balance can't cover
         let liquid_balance = vault.total_amount - vault.released_amount - vault.staked_amount;
-        if claim_amount > liquid_balance {
-            let deficit = claim_amount - liquid_balance;
-
+        let unstaked = (shares - liquid_balance).max(0);
+        if unstaked > 0 {
             let staking_contract: Address = env
                 .storage()
                 .instance()
-                .get(&Symbol::new(&env, "StakingContract"))
-                .expect("Staking contract not set");
-
-            let args = vec![&env, vault_id.into_val(&env), deficit.into_val(&env)];
-            env.invoke_contract::<()>(&staking_contract, &Symbol::new(&env, "unstake"), args);
+                .get(&Symbol::new(env, "StakingContract"))
+                .ok_or(Error::StakingContractNotSet)?;
+            let args = vec![env, vault_id.into_val(env), unstaked.into_val(env)];
+            env.invoke_contract::<()>(&staking_contract, &Symbol::new(env, "unstake"), args);
 
-            vault.staked_amount -= deficit;
-
-            let mut total_staked: i128 = env
-                .storage()
-                .instance()
-                .get(&DataKey::TotalStaked)
-                .unwrap_or(0);
-            total_staked -= deficit;
-            env.storage()
-                .instance()
-                .set(&DataKey::TotalStaked, &total_staked);
-        }
-
-        let available_to_claim = unlocked_amount - vault.released_amount;
-        if available_to_claim <= 0 {
-            panic!("No tokens available to claim");
-        }
-        if claim_amount > available_to_claim {
-            panic!("Insufficient unlocked tokens to claim");
+            vault.staked_amount -= unstaked;
+            Self::add_to_total(env, DataKey::TotalStaked, -unstaked);
         }
 
-        vault.released_amount += claim_amount;
-        env.storage()
-            .instance()
-            .set(&DataKey::VaultData(vault_id), &vault);
-
         // YIELD DISTRIBUTION - only vault-owned portion
-        let token_client = Self::get_token_client(&env);
-        let current_balance = token_client.balance(&env.current_contract_address());
-        let admin_balance: i128 = env
-            .storage()
-            .instance()
-            .get(&DataKey::AdminBalance)
-            .unwrap_or(0);
-
+        let token_client = Self::get_token_client(env)?;
         let total_shares: i128 = env
             .storage()
             .instance()
             .get(&DataKey::TotalShares)
             .unwrap_or(0);
-        let total_staked: i128 = env
+        let keeper_owed: i128 = env
             .storage()
             .instance()
-            .get(&DataKey::TotalStaked)
+            .get(&DataKey::KeeperOwed)
             .unwrap_or(0);
-        let liquid_shares = total_shares - total_staked;
-
-        let vault_portion = (current_balance - admin_balance).max(0);
-        let transfer_amount = if liquid_shares > 0 {
-            (claim_amount * vault_portion) / liquid_shares
+        let (beneficiary_shares, keeper_tokens) = if sponsored {
+            (shares, sponsored_tokens)
         } else {
-            claim_amount
+            (
+                shares - keeper_fee,
+                Self::shares_to_tokens(env, keeper_fee, owed)?,
+            )
         };
+        let amount = Self::shares_to_tokens(env, beneficiary_shares, owed)?;
 
-        vault.released_amount += claim_amount;
-        let mut updated_total_shares = total_shares;
-        updated_total_shares -= claim_amount;
+        vault.released_amount += shares;
+        Self::add_to_total(env, DataKey::TotalReleased, shares);
         env.storage()
             .instance()
-            .set(&DataKey::TotalShares, &updated_total_shares);
+            .set(&DataKey::TotalShares, &(total_shares - shares));
+        Self::set_persistent(env, &DataKey::VaultData(vault_id), &vault);
+        Self::extend_vault_entries(env, vault_id, &vault);
+
+        match deferred {
+            Some(owed) => *owed += amount,
+            None => token_client.transfer(&env.current_contract_address(), &recipient, &amount),
+        }
+
+        TokensClaimed {
+            vault_id,
+            claimant: claimant.clone(),
+            recipient,
+            shares: beneficiary_shares,
+            amount,
+            unstaked,
+            released_total: vault.released_amount,
+            timestamp: env.ledger().timestamp(),
+            version: EVENT_VERSION,
+        }
+        .publish(env);
+
+        if let ClaimPath::Keeper(keeper) = &path {
+            Self::set_persistent(
+                env,
+                &DataKey::LastAutoClaim(vault_id),
+                &env.ledger().timestamp(),
+            );
+            let mut ledger = Self::load_keeper_ledger(env, keeper);
+            ledger.claims += 1;
+            if sponsored {
+                ledger.sponsored += keeper_tokens;
+            } else {
+                ledger.deducted += keeper_tokens;
+            }
+            Self::save_keeper_ledger(env, keeper, &ledger);
+        }
+
+        if keeper_fee > 0 && sponsored {
+            // Credited to the keeper's ledger; tokens stay in the contract until withdrawn
+            env.storage()
+                .instance()
+                .set(&DataKey::KeeperPool, &(pool - keeper_tokens));
+            env.storage()
+                .instance()
+                .set(&DataKey::KeeperOwed, &(keeper_owed + keeper_tokens));
+
+            KeeperFeeSponsored {
+                vault_id,
+                keeper: claimant,
+                amount: keeper_tokens,
+                pool_balance: pool - keeper_tokens,
+                version: EVENT_VERSION,
+            }
+            .publish(env);
+        } else if keeper_fee > 0 {
+            match deferred_fee {
+                Some(owed) => *owed += keeper_tokens,
+                None => token_client.transfer(
+                    &env.current_contract_address(),
+                    &claimant,
+                    &keeper_tokens,
+                ),
+            }
+
+            if let ClaimPath::Keeper(_) = path {
+                KeeperFeePaid {
+                    vault_id,
+                    keeper: claimant,
+                    shares: keeper_fee,
+                    amount: keeper_tokens,
+                    version: EVENT_VERSION,
+                }
+                .publish(env);
+            }
+        }
+
+        Ok(ClaimOutcome {
+            shares: beneficiary_shares,
+            amount,
+            keeper_fee,
+        })
+    }
+
+    // Claim tokens from vault. Returns the shares released to the owner.
+    pub fn claim_tokens(env: Env, vault_id: u64, claim_amount: i128) -> Result<i128, Error> {
+        let outcome = Self::execute_claim(
+            &env,
+            vault_id,
+            Some(claim_amount),
+            ClaimPath::Owner,
+            None,
+            None,
+            None,
+        )?;
+        Ok(outcome.shares)
+    }
+
+    // Claim tokens from vault straight to `recipient` (e.g. an exchange
+    // deposit address). Returns the shares released.
+    pub fn claim_to(
+        env: Env,
+        vault_id: u64,
+        claim_amount: i128,
+        recipient: Address,
+    ) -> Result<i128, Error> {
+        if recipient == env.current_contract_address() {
+            return Err(Error::InvalidRecipient);
+        }
+        let outcome = Self::execute_claim(
+            &env,
+            vault_id,
+            Some(claim_amount),
+            ClaimPath::Owner,
+            Some(recipient),
+            None,
+            None,
+        )?;
+        Ok(outcome.shares)
+    }
+
+    // Owner-only: register (or with None, remove) the ed25519 public key whose
+    // signed claims any relayer may submit through `claim_with_signature`.
+    // The nonce sequence carries on across key changes.
+    pub fn set_claim_signer(
+        env: Env,
+        vault_id: u64,
+        public_key: Option<BytesN<32>>,
+    ) -> Result<(), Error> {
+        let vault: Vault = Self::load_vault(&env, vault_id)?;
+        if !vault.is_initialized {
+            return Err(Error::VaultNotInitialized);
+        }
+        vault.owner.require_auth();
+
+        let key = DataKey::ClaimSigner(vault_id);
+        match &public_key {
+            Some(public_key) => Self::set_persistent(&env, &key, public_key),
+            None => env.storage().persistent().remove(&key),
+        }
+
+        ClaimSignerSet {
+            vault_id,
+            public_key,
+            version: EVENT_VERSION,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    pub fn get_claim_signer(env: Env, vault_id: u64) -> Option<BytesN<32>> {
         env.storage()
-            .instance()
-            .set(&DataKey::VaultData(vault_id), &vault);
+            .persistent()
+            .get(&DataKey::ClaimSigner(vault_id))
+    }
 
-        token_client.transfer(
-            &env.current_contract_address(),
-            &vault.owner,
-            &transfer_amount,
-        );
+    // Nonce the vault's next signed claim must carry
+    pub fn get_claim_nonce(env: Env, vault_id: u64) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ClaimNonce(vault_id))
+            .unwrap_or(0)
+    }
+
+    // Submit a claim the owner signed off-chain with the vault's claim
+    // signer. The relayer authorizes the call and receives `relayer_fee`
+    // shares out of the claim; the rest goes to the signed recipient.
+    // Every typed check runs before the signature is verified. A signature
+    // whose scalar is out of range fails with `InvalidSignature`; any other
+    // that does not verify aborts the call, since the host traps instead of
+    // returning an error. Returns the shares released to the recipient.
+    pub fn claim_with_signature(
+        env: Env,
+        claim: SignedClaim,
+        signature: BytesN<64>,
+        relayer: Address,
+    ) -> Result<i128, Error> {
+        let vault_id = claim.vault_id;
+        let public_key: BytesN<32> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ClaimSigner(vault_id))
+            .ok_or(Error::NoClaimSigner)?;
+        let nonce = Self::get_claim_nonce(env.clone(), vault_id);
+        if claim.nonce != nonce {
+            return Err(Error::InvalidNonce);
+        }
+        if env.ledger().timestamp() >= claim.expires_at {
+            return Err(Error::SignedClaimExpired);
+        }
+        if claim.amount <= 0 || claim.relayer_fee < 0 {
+            return Err(Error::InvalidAmount);
+        }
+        if claim.relayer_fee >= claim.amount {
+            return Err(Error::InsufficientClaimableForFee);
+        }
+        if claim.recipient == env.current_contract_address() {
+            return Err(Error::InvalidRecipient);
+        }
+        if !Self::is_canonical_signature(&signature) {
+            return Err(Error::InvalidSignature);
+        }
+
+        let message = (env.current_contract_address(), claim.clone()).to_xdr(&env);
+        env.crypto()
+            .ed25519_verify(&public_key, &message, &signature);
+        Self::set_persistent(&env, &DataKey::ClaimNonce(vault_id), &(nonce + 1));
+
+        let outcome = Self::execute_claim(
+            &env,
+            vault_id,
+            Some(claim.amount),
+            ClaimPath::Relayer(relayer.clone(), claim.relayer_fee),
+            Some(claim.recipient),
+            None,
+            None,
+        )?;
+
+        SignedClaimRelayed {
+            vault_id,
+            relayer,
+            nonce,
+            relayer_fee: outcome.keeper_fee,
+            version: EVENT_VERSION,
+        }
+        .publish(&env);
+        Ok(outcome.shares)
+    }
+
+    // An ed25519 signature's second half is a scalar below the group order
+    // (little-endian); no signature with a larger one verifies
+    fn is_canonical_signature(signature: &BytesN<64>) -> bool {
+        const GROUP_ORDER: [u8; 32] = [
+            0xed, 0xd3, 0xf5, 0x5c, 0x1a, 0x63, 0x12, 0x58, 0xd6, 0x9c, 0xf7, 0xa2, 0xde, 0xf9,
+            0xde, 0x14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x10,
+        ];
+        let bytes = signature.to_array();
+        for i in (0..32).rev() {
+            let byte = bytes[32 + i];
+            if byte != GROUP_ORDER[i] {
+                return byte < GROUP_ORDER[i];
+            }
+        }
+        false
+    }
+
+    // Set (or with None, clear) where `owner`'s claims are paid when no
+    // recipient is given. Applies to every vault the owner holds.
+    pub fn set_payout_address(
+        env: Env,
+        owner: Address,
+        payout: Option<Address>,
+    ) -> Result<(), Error> {
+        owner.require_auth();
+
+        let key = DataKey::PayoutAddress(owner.clone());
+        match &payout {
+            Some(address) if *address == env.current_contract_address() => {
+                return Err(Error::InvalidRecipient);
+            }
+            Some(address) => Self::set_persistent(&env, &key, address),
+            None => env.storage().persistent().remove(&key),
+        }
+
+        PayoutAddressSet {
+            owner,
+            payout,
+            version: EVENT_VERSION,
+        }
+        .publish(&env);
+        Ok(())
+    }
 
-        claim_amount
+    pub fn get_payout_address(env: Env, owner: Address) -> Option<Address> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PayoutAddress(owner))
+    }
+
+    // Where claims for `owner` go by default: their payout address, or themselves
+    fn payout_address_for(env: &Env, owner: &Address) -> Address {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PayoutAddress(owner.clone()))
+            .unwrap_or(owner.clone())
     }
 
     /// Transfers the beneficiary role of a vault to a new address.
     /// Only the admin can perform this action (e.g., in case of lost keys).
-    pub fn transfer_beneficiary(env: Env, vault_id: u64, new_address: Address) {
-        Self::require_admin(&env);
+    pub fn transfer_beneficiary(
+        env: Env,
+        vault_id: u64,
+        new_address: Address,
+    ) -> Result<(), Error> {
+        Self::require_admin(&env)?;
 
-        let mut vault: Vault = env
-            .storage()
-            .instance()
-            .get(&DataKey::VaultData(vault_id))
-            .unwrap_or_else(|| panic!("Vault not found"));
+        let mut vault: Vault = Self::load_vault(&env, vault_id)?;
 
         let old_owner = vault.owner.clone();
 
         if vault.is_initialized {
-            let old_vaults: Vec<u64> = env
-                .storage()
-                .instance()
-                .get(&DataKey::UserVaults(old_owner.clone()))
-                .unwrap_or(Vec::new(&env));
+            let old_vaults: Vec<u64> =
+                Self::get_persistent(&env, &DataKey::UserVaults(old_owner.clone()))
+                    .unwrap_or(Vec::new(&env));
 
             let mut updated_old_vaults = Vec::new(&env);
             for id in old_vaults.iter() {
@@ -982,162 +2461,552 @@ fn calculate_time_vested_amount(env: &Env, vault: &Vault) -> i128 {
                     updated_old_vaults.push_back(id);
                 }
             }
-            env.storage()
-                .instance()
-                .set(&DataKey::UserVaults(old_owner.clone()), &updated_old_vaults);
+            Self::set_persistent(
+                &env,
+                &DataKey::UserVaults(old_owner.clone()),
+                &updated_old_vaults,
+            );
 
-            let mut new_vaults: Vec<u64> = env
-                .storage()
-                .instance()
-                .get(&DataKey::UserVaults(new_address.clone()))
-                .unwrap_or(Vec::new(&env));
+            let mut new_vaults: Vec<u64> =
+                Self::get_persistent(&env, &DataKey::UserVaults(new_address.clone()))
+                    .unwrap_or(Vec::new(&env));
             new_vaults.push_back(vault_id);
-            env.storage()
-                .instance()
-                .set(&DataKey::UserVaults(new_address.clone()), &new_vaults);
+            Self::set_persistent(&env, &DataKey::UserVaults(new_address.clone()), &new_vaults);
         }
 
         vault.owner = new_address.clone();
+        // The previous owner's claim signer and auto-claim policy no longer
+        // speak for the vault
         env.storage()
-            .instance()
-            .set(&DataKey::VaultData(vault_id), &vault);
+            .persistent()
+            .remove(&DataKey::ClaimSigner(vault_id));
+        Self::clear_auto_claim_policy(&env, vault_id);
+        if let Some(old_delegate) = Self::clear_delegates(&env, vault_id, &mut vault) {
+            DelegateUpdated {
+                vault_id,
+                old_delegate: Some(old_delegate),
+                new_delegate: None,
+                version: EVENT_VERSION,
+            }
+            .publish(&env);
+        }
+        Self::set_persistent(&env, &DataKey::VaultData(vault_id), &vault);
 
-        env.events().publish(
-            (Symbol::new(&env, "BeneficiaryUpdated"), vault_id),
-            (old_owner.clone(), new_address),
-        );
+        Self::record_admin_action(
+            &env,
+            AdminAction::BeneficiaryTransferred,
+            Some(vault_id),
+            vec![&env, old_owner.into_val(&env), new_address.into_val(&env)],
+        )?;
+
+        BeneficiaryUpdated {
+            vault_id,
+            old_owner,
+            new_owner: new_address,
+            timestamp: env.ledger().timestamp(),
+            version: EVENT_VERSION,
+        }
+        .publish(&env);
+
+        Ok(())
     }
 
     // Set delegate address for a vault (only owner can call)
-    pub fn set_delegate(env: Env, vault_id: u64, delegate: Option<Address>) {
-        let mut vault: Vault = env
-            .storage()
-            .instance()
-            .get(&DataKey::VaultData(vault_id))
-            .unwrap_or_else(|| panic!("Vault not found"));
+    pub fn set_delegate(env: Env, vault_id: u64, delegate: Option<Address>) -> Result<(), Error> {
+        let mut vault: Vault = Self::load_vault(&env, vault_id)?;
 
         if !vault.is_initialized {
-            panic!("Vault not initialized");
+            return Err(Error::VaultNotInitialized);
         }
 
         vault.owner.require_auth();
 
+        // Replaces every delegate, so the vault ends up with at most this one
         let old_delegate = vault.delegate.clone();
+        let old_delegates = Self::delegates_of(&env, vault_id, &vault);
+        for old in old_delegates.iter() {
+            if delegate.as_ref() == Some(&old) {
+                continue;
+            }
+            Self::drop_delegate(&env, &old, vault_id);
+            DelegateRemoved {
+                vault_id,
+                delegate: old,
+                version: EVENT_VERSION,
+            }
+            .publish(&env);
+        }
+        let mut delegates = Vec::new(&env);
+        if let Some(new) = &delegate {
+            if !old_delegates.contains(new) {
+                Self::index_delegate(&env, new, vault_id);
+            }
+            delegates.push_back(new.clone());
+        }
+        Self::store_delegates(&env, vault_id, &mut vault, &delegates);
+        Self::set_persistent(&env, &DataKey::VaultData(vault_id), &vault);
+
+        DelegateUpdated {
+            vault_id,
+            old_delegate,
+            new_delegate: delegate,
+            version: EVENT_VERSION,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    // Owner-only: add another delegate with its own permissions (None for
+    // unlimited claims and no staking). The first delegate becomes the primary
+    // one that `claim_as_delegate` and `stake_as_delegate` act as.
+    pub fn add_delegate(
+        env: Env,
+        vault_id: u64,
+        delegate: Address,
+        permissions: Option<DelegatePermissions>,
+    ) -> Result<(), Error> {
+        let mut vault: Vault = Self::load_vault(&env, vault_id)?;
+        if !vault.is_initialized {
+            return Err(Error::VaultNotInitialized);
+        }
+        vault.owner.require_auth();
+
+        let mut delegates = Self::delegates_of(&env, vault_id, &vault);
+        if delegates.contains(&delegate) {
+            return Err(Error::DelegateAlreadyAdded);
+        }
+        if delegates.len() >= MAX_DELEGATES {
+            return Err(Error::TooManyDelegates);
+        }
+        if permissions
+            .as_ref()
+            .is_some_and(|p| p.claim_cap.is_some_and(|cap| cap < 0))
+        {
+            return Err(Error::InvalidAmount);
+        }
+
+        let old_primary = vault.delegate.clone();
+        delegates.push_back(delegate.clone());
+        Self::store_delegates(&env, vault_id, &mut vault, &delegates);
+        Self::set_persistent(&env, &DataKey::VaultData(vault_id), &vault);
+        Self::index_delegate(&env, &delegate, vault_id);
+        if let Some(p) = &permissions {
+            Self::set_persistent(
+                &env,
+                &DataKey::DelegatePermissions(vault_id, delegate.clone()),
+                p,
+            );
+        }
+
+        DelegateAdded {
+            vault_id,
+            delegate,
+            permissions,
+            version: EVENT_VERSION,
+        }
+        .publish(&env);
+        if old_primary != vault.delegate {
+            DelegateUpdated {
+                vault_id,
+                old_delegate: old_primary,
+                new_delegate: vault.delegate.clone(),
+                version: EVENT_VERSION,
+            }
+            .publish(&env);
+        }
+        Ok(())
+    }
+
+    // Owner-only: remove one delegate and its permissions. Removing the
+    // primary delegate promotes the next one.
+    pub fn remove_delegate(env: Env, vault_id: u64, delegate: Address) -> Result<(), Error> {
+        let mut vault: Vault = Self::load_vault(&env, vault_id)?;
+        vault.owner.require_auth();
 
-        vault.delegate = delegate.clone();
+        let mut delegates = Self::delegates_of(&env, vault_id, &vault);
+        let index = delegates
+            .first_index_of(&delegate)
+            .ok_or(Error::NoDelegate)?;
+
+        let old_primary = vault.delegate.clone();
+        delegates.remove(index);
+        Self::store_delegates(&env, vault_id, &mut vault, &delegates);
+        Self::set_persistent(&env, &DataKey::VaultData(vault_id), &vault);
+        Self::drop_delegate(&env, &delegate, vault_id);
+
+        DelegateRemoved {
+            vault_id,
+            delegate,
+            version: EVENT_VERSION,
+        }
+        .publish(&env);
+        if old_primary != vault.delegate {
+            DelegateUpdated {
+                vault_id,
+                old_delegate: old_primary,
+                new_delegate: vault.delegate.clone(),
+                version: EVENT_VERSION,
+            }
+            .publish(&env);
+        }
+        Ok(())
+    }
+
+    // Every delegate of a vault, primary first
+    pub fn get_delegates(env: Env, vault_id: u64) -> Result<Vec<Address>, Error> {
+        let vault: Vault = Self::load_vault(&env, vault_id)?;
+        Ok(Self::delegates_of(&env, vault_id, &vault))
+    }
+
+    // Vaults whose delegate was set before delegate sets existed only have `vault.delegate`
+    fn delegates_of(env: &Env, vault_id: u64, vault: &Vault) -> Vec<Address> {
         env.storage()
-            .instance()
-            .set(&DataKey::VaultData(vault_id), &vault);
+            .persistent()
+            .get(&DataKey::VaultDelegates(vault_id))
+            .unwrap_or_else(|| match &vault.delegate {
+                Some(delegate) => vec![env, delegate.clone()],
+                None => Vec::new(env),
+            })
+    }
 
-        env.events().publish(
-            (Symbol::new(&env, "DelegateUpdated"), vault_id),
-            (old_delegate, delegate),
-        );
+    // Write the delegate set and mirror its first entry into `vault.delegate`;
+    // the caller saves the vault
+    fn store_delegates(env: &Env, vault_id: u64, vault: &mut Vault, delegates: &Vec<Address>) {
+        let key = DataKey::VaultDelegates(vault_id);
+        vault.delegate = delegates.first();
+        if delegates.is_empty() {
+            env.storage().persistent().remove(&key);
+        } else {
+            Self::set_persistent(env, &key, delegates);
+        }
     }
 
-    // Claim tokens as delegate (tokens still go to owner)
-    pub fn claim_as_delegate(env: Env, vault_id: u64, claim_amount: i128) -> i128 {
-        if Self::is_paused(env.clone()) {
-            panic!("Contract is paused");
-        // Check if contract is paused
-        if Self::is_paused(env.clone()) {
-            panic!("Contract is paused - all withdrawals are disabled");
+    // Remove every delegate, e.g. when the vault changes hands. Returns the
+    // former primary delegate so the caller can report the change.
+    fn clear_delegates(env: &Env, vault_id: u64, vault: &mut Vault) -> Option<Address> {
+        let primary = vault.delegate.clone();
+        for delegate in Self::delegates_of(env, vault_id, vault).iter() {
+            Self::drop_delegate(env, &delegate, vault_id);
+            DelegateRemoved {
+                vault_id,
+                delegate,
+                version: EVENT_VERSION,
+            }
+            .publish(env);
         }
+        Self::store_delegates(env, vault_id, vault, &Vec::new(env));
+        primary
+    }
 
-        let mut vault: Vault = env
-            .storage()
-            .instance()
-            .get(&DataKey::VaultData(vault_id))
-            .unwrap_or_else(|| panic!("Vault not found"));
+    // The acting delegate: `delegate` if given, otherwise the primary one.
+    // Fails with `NoDelegate` if it isn't one of the vault's delegates.
+    fn resolve_delegate(
+        env: &Env,
+        vault_id: u64,
+        vault: &Vault,
+        delegate: Option<Address>,
+    ) -> Result<Address, Error> {
+        match delegate {
+            Some(delegate) if Self::delegates_of(env, vault_id, vault).contains(&delegate) => {
+                Ok(delegate)
+            }
+            Some(_) => Err(Error::NoDelegate),
+            None => vault.delegate.clone().ok_or(Error::NoDelegate),
+        }
+    }
 
-        // Check if vault is frozen
-        if vault.is_frozen {
-            panic!("Vault is frozen - claims are disabled");
+    // Owner-only: limit what `delegate` may do on the vault, or with None
+    // restore the default of unlimited claims and no staking. Usage within
+    // the current cap window is kept.
+    pub fn set_delegate_permissions(
+        env: Env,
+        vault_id: u64,
+        delegate: Address,
+        permissions: Option<DelegatePermissions>,
+    ) -> Result<(), Error> {
+        let vault: Vault = Self::load_vault(&env, vault_id)?;
+        vault.owner.require_auth();
+        if !Self::delegates_of(&env, vault_id, &vault).contains(&delegate) {
+            return Err(Error::NoDelegate);
         }
 
-        if !vault.is_initialized {
-            panic!("Vault not initialized");
+        let key = DataKey::DelegatePermissions(vault_id, delegate.clone());
+        match &permissions {
+            Some(p) if p.claim_cap.is_some_and(|cap| cap < 0) => {
+                return Err(Error::InvalidAmount);
+            }
+            Some(p) => Self::set_persistent(&env, &key, p),
+            None => env.storage().persistent().remove(&key),
         }
-        if claim_amount <= 0 {
-            panic!("Claim amount must be positive");
+
+        DelegatePermissionsSet {
+            vault_id,
+            delegate,
+            permissions,
+            version: EVENT_VERSION,
         }
+        .publish(&env);
+        Ok(())
+    }
 
-        let delegate = vault
-            .delegate
-            .clone()
-            .unwrap_or_else(|| panic!("No delegate set for this vault"));
-        delegate.require_auth();
+    pub fn get_delegate_permissions(
+        env: Env,
+        vault_id: u64,
+        delegate: Address,
+    ) -> Option<DelegatePermissions> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::DelegatePermissions(vault_id, delegate))
+    }
 
-        let unlocked_amount = if env
+    // Shares `delegate` may still claim in the current cap window; None if uncapped
+    pub fn get_delegate_allowance(env: Env, vault_id: u64, delegate: Address) -> Option<i128> {
+        let permissions: DelegatePermissions = env
             .storage()
-            .instance()
-            .has(&DataKey::VaultMilestones(vault_id))
+            .persistent()
+            .get(&DataKey::DelegatePermissions(vault_id, delegate.clone()))?;
+        let cap = permissions.claim_cap?;
+        let usage = Self::current_delegate_usage(&env, vault_id, &delegate, &permissions);
+        Some((cap - usage.claimed).max(0))
+    }
+
+    // Fails unless `delegate`'s permissions (if any) allow the operation right now
+    fn require_delegate_permitted(
+        env: &Env,
+        vault_id: u64,
+        delegate: &Address,
+        stake: bool,
+    ) -> Result<(), Error> {
+        let permissions: Option<DelegatePermissions> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::DelegatePermissions(vault_id, delegate.clone()));
+        let permissions = match permissions {
+            Some(permissions) => permissions,
+            None if stake => return Err(Error::DelegateNotPermitted),
+            None => return Ok(()),
+        };
+        if permissions
+            .expires_at
+            .is_some_and(|expiry| env.ledger().timestamp() >= expiry)
         {
-            let milestones = Self::require_milestones_configured(&env, vault_id);
-            let unlocked_pct = Self::unlocked_percentage(&milestones);
-            Self::unlocked_amount(vault.total_amount, unlocked_pct)
+            return Err(Error::DelegateExpired);
+        }
+        let allowed = if stake {
+            permissions.can_stake
         } else {
-            Self::calculate_time_vested_amount(&env, &vault)
+            permissions.can_claim
         };
-        let available_to_claim = unlocked_amount - vault.released_amount;
-        if available_to_claim <= 0 {
-            panic!("No tokens available to claim");
+        if !allowed {
+            return Err(Error::DelegateNotPermitted);
         }
-        if claim_amount > available_to_claim {
-            panic!("Insufficient unlocked tokens to claim");
+        Ok(())
+    }
+
+    // Count `shares` against the delegate's claim cap, starting a new window once the old one lapses
+    fn consume_delegate_allowance(
+        env: &Env,
+        vault_id: u64,
+        delegate: &Address,
+        shares: i128,
+    ) -> Result<(), Error> {
+        let permissions: DelegatePermissions = match env
+            .storage()
+            .persistent()
+            .get(&DataKey::DelegatePermissions(vault_id, delegate.clone()))
+        {
+            Some(permissions) => permissions,
+            None => return Ok(()),
+        };
+        let cap = match permissions.claim_cap {
+            Some(cap) => cap,
+            None => return Ok(()),
+        };
+
+        let mut usage = Self::current_delegate_usage(env, vault_id, delegate, &permissions);
+        if usage.claimed + shares > cap {
+            return Err(Error::DelegateCapExceeded);
         }
+        usage.claimed += shares;
+        Self::set_persistent(
+            env,
+            &DataKey::DelegateUsage(vault_id, delegate.clone()),
+            &usage,
+        );
+        Ok(())
+    }
 
-        // YIELD DISTRIBUTION - only vault-owned portion
-        let token_client = Self::get_token_client(&env);
-        let current_balance = token_client.balance(&env.current_contract_address());
-        let admin_balance: i128 = env
+    fn current_delegate_usage(
+        env: &Env,
+        vault_id: u64,
+        delegate: &Address,
+        permissions: &DelegatePermissions,
+    ) -> DelegateUsage {
+        let now = env.ledger().timestamp();
+        let usage: Option<DelegateUsage> = env
             .storage()
-            .instance()
-            .get(&DataKey::AdminBalance)
-            .unwrap_or(0);
+            .persistent()
+            .get(&DataKey::DelegateUsage(vault_id, delegate.clone()));
+        match usage {
+            Some(usage)
+                if permissions.period == 0
+                    || now < usage.period_start.saturating_add(permissions.period) =>
+            {
+                usage
+            }
+            _ => DelegateUsage {
+                period_start: now,
+                claimed: 0,
+            },
+        }
+    }
 
-        let total_shares: i128 = env
+    // Page through the vault IDs a delegate is currently allowed to claim for
+    pub fn get_delegate_vaults(env: Env, delegate: Address, offset: u32, limit: u32) -> Vec<u64> {
+        let vault_ids: Vec<u64> = env
             .storage()
-            .instance()
-            .get(&DataKey::TotalShares)
-            .unwrap_or(0);
-        let total_staked: i128 = env
+            .persistent()
+            .get(&DataKey::DelegateVaults(delegate))
+            .unwrap_or(Vec::new(&env));
+        let end = vault_ids
+            .len()
+            .min(offset.saturating_add(limit.min(MAX_PAGE_SIZE)));
+        if offset >= end {
+            return Vec::new(&env);
+        }
+        vault_ids.slice(offset..end)
+    }
+
+    fn index_delegate(env: &Env, delegate: &Address, vault_id: u64) {
+        let key = DataKey::DelegateVaults(delegate.clone());
+        let mut vault_ids: Vec<u64> = env
             .storage()
-            .instance()
-            .get(&DataKey::TotalStaked)
-            .unwrap_or(0);
-        let liquid_shares = total_shares - total_staked;
+            .persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(env));
+        vault_ids.push_back(vault_id);
+        Self::set_persistent(env, &key, &vault_ids);
+    }
+
+    // Remove a former delegate from the index, together with the permissions
+    // and usage it held on the vault
+    fn drop_delegate(env: &Env, delegate: &Address, vault_id: u64) {
+        let storage = env.storage().persistent();
+        storage.remove(&DataKey::DelegatePermissions(vault_id, delegate.clone()));
+        storage.remove(&DataKey::DelegateUsage(vault_id, delegate.clone()));
 
-        let vault_portion = (current_balance - admin_balance).max(0);
-        let transfer_amount = if liquid_shares > 0 {
-            (claim_amount * vault_portion) / liquid_shares
+        let key = DataKey::DelegateVaults(delegate.clone());
+        let mut vault_ids: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(env));
+        if let Some(index) = vault_ids.first_index_of(vault_id) {
+            vault_ids.remove(index);
+        }
+        if vault_ids.is_empty() {
+            env.storage().persistent().remove(&key);
         } else {
-            claim_amount
-        };
+            Self::set_persistent(env, &key, &vault_ids);
+        }
+    }
 
-        let mut updated_vault = vault.clone();
-        updated_vault.released_amount += claim_amount;
-        env.storage()
+    // Claim everything available from every vault `owner` holds, optionally
+    // only vaults paying out `token`. Frozen vaults and vaults with nothing
+    // unlocked are skipped. Tokens are paid to the owner's payout address in
+    // one transfer per token; the per-vault breakdown is returned in `UserVaults` order.
+    pub fn claim_all(
+        env: Env,
+        owner: Address,
+        token: Option<Address>,
+    ) -> Result<Vec<VaultClaim>, Error> {
+        owner.require_auth();
+        Self::require_not_deprecated(&env)?;
+        Self::require_scope_active(&env, PauseScope::Claims)?;
+
+        // Every vault pays out the contract's configured token. The filter lets
+        // a caller name the token it expects to receive: if that isn't the
+        // contract's token, nothing is claimed rather than paying out another.
+        let vault_token: Address = env
+            .storage()
             .instance()
-            .set(&DataKey::VaultData(vault_id), &updated_vault);
+            .get(&DataKey::Token)
+            .ok_or(Error::TokenNotSet)?;
+        let mut claims: Vec<VaultClaim> = Vec::new(&env);
+        if token.is_some_and(|t| t != vault_token) {
+            return Ok(claims);
+        }
 
-        claim_amount
-        let mut updated_total_shares = total_shares;
-        updated_total_shares -= claim_amount;
-        env.storage()
-            .instance()
-            .set(&DataKey::TotalShares, &updated_total_shares);
-        env.storage()
-            .instance()
-            .set(&DataKey::VaultData(vault_id), &updated_vault);
+        let vault_ids: Vec<u64> = Self::get_persistent(&env, &DataKey::UserVaults(owner.clone()))
+            .unwrap_or(Vec::new(&env));
+        let mut total: i128 = 0;
+        for vault_id in vault_ids.iter() {
+            let claimed = Self::execute_claim(
+                &env,
+                vault_id,
+                None,
+                ClaimPath::AuthorizedOwner(owner.clone()),
+                None,
+                Some(&mut total),
+                None,
+            );
+            match claimed {
+                Ok(outcome) => {
+                    claims.push_back(VaultClaim {
+                        vault_id,
+                        token: vault_token.clone(),
+                        shares: outcome.shares,
+                        amount: outcome.amount,
+                    });
+                }
+                Err(Error::VaultFrozen | Error::NothingToClaim) => {}
+                Err(e) => return Err(e),
+            }
+        }
 
-        token_client.transfer(
-            &env.current_contract_address(),
-            &updated_vault.owner,
-            &transfer_amount,
-        );
+        if total > 0 {
+            let recipient = Self::payout_address_for(&env, &owner);
+            token::Client::new(&env, &vault_token).transfer(
+                &env.current_contract_address(),
+                &recipient,
+                &total,
+            );
+        }
+        Ok(claims)
+    }
+
+    // Claim tokens as delegate (tokens still go to owner)
+    pub fn claim_as_delegate(env: Env, vault_id: u64, claim_amount: i128) -> Result<i128, Error> {
+        let outcome = Self::execute_claim(
+            &env,
+            vault_id,
+            Some(claim_amount),
+            ClaimPath::Delegate(None),
+            None,
+            None,
+            None,
+        )?;
+        Ok(outcome.shares)
+    }
 
-        transfer_amount
+    // Claim as any one of the vault's delegates (tokens still go to owner)
+    pub fn delegate_claim(
+        env: Env,
+        vault_id: u64,
+        delegate: Address,
+        claim_amount: i128,
+    ) -> Result<i128, Error> {
+        let outcome = Self::execute_claim(
+            &env,
+            vault_id,
+            Some(claim_amount),
+            ClaimPath::Delegate(Some(delegate)),
+            None,
+            None,
+            None,
+        )?;
+        Ok(outcome.shares)
     }
 
     /// Claim vested tokens and atomically invoke a target contract within the
@@ -1165,211 +3034,112 @@ fn calculate_time_vested_amount(env: &Env, vault: &Vault) -> i128 {
         target_contract: Address,
         function: Symbol,
         args: Vec<Val>,
-    ) -> i128 {
-        Self::require_not_deprecated(&env);
-
-        if Self::is_paused(env.clone()) {
-            panic!("Contract is paused - all operations are disabled");
-        }
-
-        let mut vault: Vault = env
-            .storage()
-            .instance()
-            .get(&DataKey::VaultData(vault_id))
-            .unwrap_or_else(|| panic!("Vault not found"));
-
-        if vault.is_frozen {
-            panic!("Vault is frozen - claims are disabled");
-        }
-        if !vault.is_initialized {
-            panic!("Vault not initialized");
-        }
-        if claim_amount <= 0 {
-            panic!("Claim amount must be positive");
-        }
-
-        // Only the vault owner can trigger claim_and_call
-        vault.owner.require_auth();
-
-        // --- vesting / milestone unlock calculation (mirrors claim_tokens) ---
-        let unlocked_amount = if env
-            .storage()
-            .instance()
-            .has(&DataKey::VaultMilestones(vault_id))
-        {
-            let milestones = Self::require_milestones_configured(&env, vault_id);
-            let unlocked_pct = Self::unlocked_percentage(&milestones);
-            Self::unlocked_amount(vault.total_amount, unlocked_pct)
-        } else {
-            Self::calculate_time_vested_amount(&env, &vault)
-        };
-
-        // Auto-unstake if liquid balance is insufficient
-        let liquid_balance = vault.total_amount - vault.released_amount - vault.staked_amount;
-        if claim_amount > liquid_balance {
-            let deficit = claim_amount - liquid_balance;
-
-            let staking_contract: Address = env
-                .storage()
-                .instance()
-                .get(&Symbol::new(&env, "StakingContract"))
-                .expect("Staking contract not set");
-
-            let unstake_args =
-                vec![&env, vault_id.into_val(&env), deficit.into_val(&env)];
-            env.invoke_contract::<()>(
-                &staking_contract,
-                &Symbol::new(&env, "unstake"),
-                unstake_args,
-            );
-
-            vault.staked_amount -= deficit;
-
-            let mut total_staked: i128 = env
-                .storage()
-                .instance()
-                .get(&DataKey::TotalStaked)
-                .unwrap_or(0);
-            total_staked -= deficit;
-            env.storage()
-                .instance()
-                .set(&DataKey::TotalStaked, &total_staked);
-        }
-
-        let available_to_claim = unlocked_amount - vault.released_amount;
-        if available_to_claim <= 0 {
-            panic!("No tokens available to claim");
-        }
-        if claim_amount > available_to_claim {
-            panic!("Insufficient unlocked tokens to claim");
-        }
-
-        // --- yield distribution (mirrors claim_tokens) -----------------------
-        let token_client = Self::get_token_client(&env);
-        let current_balance = token_client.balance(&env.current_contract_address());
-        let admin_balance: i128 = env
-            .storage()
-            .instance()
-            .get(&DataKey::AdminBalance)
-            .unwrap_or(0);
-
-        let total_shares: i128 = env
-            .storage()
-            .instance()
-            .get(&DataKey::TotalShares)
-            .unwrap_or(0);
-        let total_staked: i128 = env
-            .storage()
-            .instance()
-            .get(&DataKey::TotalStaked)
-            .unwrap_or(0);
-        let liquid_shares = total_shares - total_staked;
-
-        let vault_portion = (current_balance - admin_balance).max(0);
-        let transfer_amount = if liquid_shares > 0 {
-            (claim_amount * vault_portion) / liquid_shares
-        } else {
-            claim_amount
-        };
-
-        // --- update vault accounting ----------------------------------------
-        vault.released_amount += claim_amount;
-        let updated_total_shares = total_shares - claim_amount;
-        env.storage()
-            .instance()
-            .set(&DataKey::TotalShares, &updated_total_shares);
-        env.storage()
-            .instance()
-            .set(&DataKey::VaultData(vault_id), &vault);
-
-        // --- transfer tokens to target_contract (not the beneficiary) -------
-        token_client.transfer(
-            &env.current_contract_address(),
-            &target_contract,
-            &transfer_amount,
-        );
+    ) -> Result<i128, Error> {
+        let outcome = Self::execute_claim(
+            &env,
+            vault_id,
+            Some(claim_amount),
+            ClaimPath::Owner,
+            Some(target_contract.clone()),
+            None,
+            None,
+        )?;
 
         // --- cross-contract callback ----------------------------------------
         let _ = env.invoke_contract::<Val>(&target_contract, &function, args);
 
-        // --- event -----------------------------------------------------------
-        env.events().publish(
-            (Symbol::new(&env, "ClaimAndCall"), vault_id),
-            (vault.owner, target_contract, function, transfer_amount),
-        );
+        ClaimCallbackInvoked {
+            vault_id,
+            target_contract,
+            function,
+            amount: outcome.amount,
+            version: EVENT_VERSION,
+        }
+        .publish(&env);
 
-        transfer_amount
+        Ok(outcome.shares)
     }
 
-    pub fn set_milestones(env: Env, vault_id: u64, milestones: Vec<Milestone>) {
-        Self::require_admin(&env);
+    pub fn set_milestones(
+        env: Env,
+        vault_id: u64,
+        milestones: Vec<Milestone>,
+    ) -> Result<(), Error> {
+        Self::require_admin(&env)?;
 
-        let vault: Vault = env
-            .storage()
-            .instance()
-            .get(&DataKey::VaultData(vault_id))
-            .unwrap_or_else(|| panic!("Vault not found"));
+        let vault: Vault = Self::load_vault(&env, vault_id)?;
         if !vault.is_initialized {
-            panic!("Vault not initialized");
+            return Err(Error::VaultNotInitialized);
         }
 
         if milestones.is_empty() {
-            panic!("No milestones provided");
+            return Err(Error::NoMilestones);
         }
 
         let mut total_pct: u32 = 0;
         let mut seen: Map<u64, bool> = Map::new(&env);
         for m in milestones.iter() {
             if m.percentage == 0 {
-                panic!("Milestone percentage must be positive");
+                return Err(Error::InvalidMilestonePercentage);
             }
             if m.percentage > 100 {
-                panic!("Milestone percentage too large");
+                return Err(Error::MilestonePercentageTooLarge);
             }
             if seen.contains_key(m.id) {
-                panic!("Duplicate milestone id");
+                return Err(Error::DuplicateMilestone);
             }
             seen.set(m.id, true);
             total_pct = total_pct.saturating_add(m.percentage);
         }
         if total_pct > 100 {
-            panic!("Total milestone percentage exceeds 100");
+            return Err(Error::MilestoneTotalExceeds100);
         }
 
-        env.storage()
-            .instance()
-            .set(&DataKey::VaultMilestones(vault_id), &milestones);
-        env.events().publish(
-            (Symbol::new(&env, "MilestonesSet"), vault_id),
-            (milestones.len(), total_pct),
-        );
+        Self::set_persistent(&env, &DataKey::VaultMilestones(vault_id), &milestones);
+        Self::record_admin_action(
+            &env,
+            AdminAction::MilestonesSet,
+            Some(vault_id),
+            vec![
+                &env,
+                milestones.len().into_val(&env),
+                total_pct.into_val(&env),
+            ],
+        )?;
+
+        MilestonesSet {
+            vault_id,
+            milestones: milestones.clone(),
+            milestone_count: milestones.len(),
+            total_percentage: total_pct,
+            version: EVENT_VERSION,
+        }
+        .publish(&env);
+
+        Ok(())
     }
 
     pub fn get_milestones(env: Env, vault_id: u64) -> Vec<Milestone> {
-        env.storage()
-            .instance()
-            .get(&DataKey::VaultMilestones(vault_id))
-            .unwrap_or(Vec::new(&env))
+        Self::get_persistent(&env, &DataKey::VaultMilestones(vault_id)).unwrap_or(Vec::new(&env))
     }
 
-    pub fn unlock_milestone(env: Env, vault_id: u64, milestone_id: u64) {
-        Self::require_admin(&env);
+    pub fn unlock_milestone(env: Env, vault_id: u64, milestone_id: u64) -> Result<(), Error> {
+        Self::require_admin(&env)?;
+        Self::internal_unlock_milestone(&env, vault_id, milestone_id)
+    }
 
-        let _vault: Vault = env
-            .storage()
-            .instance()
-            .get(&DataKey::VaultData(vault_id))
-            .unwrap_or_else(|| panic!("Vault not found"));
+    fn internal_unlock_milestone(env: &Env, vault_id: u64, milestone_id: u64) -> Result<(), Error> {
+        let _vault: Vault = Self::load_vault(env, vault_id)?;
 
-        let milestones = Self::require_milestones_configured(&env, vault_id);
+        let milestones = Self::require_milestones_configured(env, vault_id)?;
 
         let mut found = false;
-        let mut updated = Vec::new(&env);
+        let mut updated = Vec::new(env);
         for m in milestones.iter() {
             if m.id == milestone_id {
                 found = true;
                 if m.is_unlocked {
-                    panic!("Milestone already unlocked");
+                    return Err(Error::MilestoneAlreadyUnlocked);
                 }
                 updated.push_back(Milestone {
                     id: m.id,
@@ -1381,41 +3151,68 @@ fn calculate_time_vested_amount(env: &Env, vault: &Vault) -> i128 {
             }
         }
         if !found {
-            panic!("Milestone not found");
+            return Err(Error::MilestoneNotFound);
         }
 
-        env.storage()
-            .instance()
-            .set(&DataKey::VaultMilestones(vault_id), &updated);
+        Self::set_persistent(env, &DataKey::VaultMilestones(vault_id), &updated);
+        Self::record_admin_action(
+            env,
+            AdminAction::MilestoneUnlocked,
+            Some(vault_id),
+            vec![env, milestone_id.into_val(env)],
+        )?;
+
         let timestamp = env.ledger().timestamp();
-        env.events().publish(
-            (Symbol::new(&env, "MilestoneUnlocked"), vault_id),
-            (milestone_id, timestamp),
-        );
+        MilestoneUnlocked {
+            vault_id,
+            milestone_id,
+            timestamp,
+            version: EVENT_VERSION,
+        }
+        .publish(env);
+
+        Ok(())
     }
 
     // Admin-only: set a short title for a vault (max 32 bytes)
-    pub fn set_vault_title(env: Env, vault_id: u64, title: String) {
-        Self::require_admin(&env);
+    pub fn set_vault_title(env: Env, vault_id: u64, title: String) -> Result<(), Error> {
+        Self::require_admin(&env)?;
 
         // Enforce max length (32 bytes)
         if title.len() > 32 {
-            panic!("Title too long");
+            return Err(Error::TitleTooLong);
         }
 
-        let mut vault: Vault = env
-            .storage()
-            .instance()
-            .get(&DataKey::VaultData(vault_id))
-            .unwrap_or_else(|| panic!("Vault not found"));
+        let mut vault: Vault = Self::load_vault(&env, vault_id)?;
+
+        vault.title = title.clone();
+        Self::set_persistent(&env, &DataKey::VaultData(vault_id), &vault);
+
+        Self::record_admin_action(
+            &env,
+            AdminAction::TitleSet,
+            Some(vault_id),
+            vec![&env, title.into_val(&env)],
+        )?;
+
+        VaultTitleSet {
+            vault_id,
+            title,
+            version: EVENT_VERSION,
+        }
+        .publish(&env);
 
-        vault.title = title;
-        env.storage().instance().set(&DataKey::VaultData(vault_id), &vault);
+        Ok(())
     }
 
     // Batch create vaults with lazy initialization
-    pub fn batch_create_vaults_lazy(env: Env, batch_data: BatchCreateData) -> Vec<u64> {
-        Self::require_admin(&env);
+    pub fn batch_create_vaults_lazy(
+        env: Env,
+        batch_data: BatchCreateData,
+    ) -> Result<Vec<u64>, Error> {
+        Self::require_admin(&env)?;
+        Self::require_scope_active(&env, PauseScope::VaultCreation)?;
+        Self::require_valid_batch(&batch_data)?;
 
         let mut vault_ids = Vec::new(&env);
         let initial_count: u64 = env
@@ -1424,6 +3221,94 @@ fn calculate_time_vested_amount(env: &Env, vault: &Vault) -> i128 {
             .get(&DataKey::VaultCount)
             .unwrap_or(0);
 
+        let total_amount: i128 = batch_data.amounts.iter().sum();
+        let mut admin_balance: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::AdminBalance)
+            .unwrap_or(0);
+        if admin_balance < total_amount {
+            return Err(Error::InsufficientAdminBalance);
+        }
+        admin_balance -= total_amount;
+        env.storage()
+            .instance()
+            .set(&DataKey::AdminBalance, &admin_balance);
+
+        let now = env.ledger().timestamp();
+        for i in 0..batch_data.recipients.len() {
+            let vault_id = initial_count + i as u64 + 1;
+            let start_time: u64 = batch_data.start_times.get(i).unwrap();
+            let end_time: u64 = batch_data.end_times.get(i).unwrap();
+            Self::require_valid_duration(start_time, end_time)?;
+
+            let vault = Vault {
+                total_amount: batch_data.amounts.get(i).unwrap(),
+                released_amount: 0,
+                keeper_fee: batch_data.keeper_fees.get(i).unwrap(),
+                staked_amount: 0,
+                owner: batch_data.recipients.get(i).unwrap(),
+                delegate: None,
+                title: String::from_str(&env, ""),
+                start_time,
+                end_time,
+                creation_time: now,
+                step_duration: batch_data.step_durations.get(i).unwrap_or(0),
+                is_initialized: false, // Lazy initialization
+                is_irrevocable: false, // Default to revocable for batch operations
+                is_transferable: false,
+                is_frozen: false,
+            };
+
+            Self::set_persistent(&env, &DataKey::VaultData(vault_id), &vault);
+            Self::extend_vault_entries(&env, vault_id, &vault);
+            vault_ids.push_back(vault_id);
+
+            Self::record_admin_action(
+                &env,
+                AdminAction::VaultCreated,
+                Some(vault_id),
+                vec![
+                    &env,
+                    vault.owner.into_val(&env),
+                    vault.total_amount.into_val(&env),
+                    start_time.into_val(&env),
+                    end_time.into_val(&env),
+                ],
+            )?;
+
+            Self::emit_vault_created(&env, vault_id, &vault);
+        }
+
+        let mut total_shares: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::TotalShares)
+            .unwrap_or(0);
+        total_shares += total_amount;
+        env.storage()
+            .instance()
+            .set(&DataKey::TotalShares, &total_shares);
+
+        let final_count = initial_count + batch_data.recipients.len() as u64;
+        env.storage()
+            .instance()
+            .set(&DataKey::VaultCount, &final_count);
+
+        Self::record_cohort(&env, &vault_ids);
+
+        Ok(vault_ids)
+    }
+
+    // Batch create vaults with full initialization
+    pub fn batch_create_vaults_full(
+        env: Env,
+        batch_data: BatchCreateData,
+    ) -> Result<Vec<u64>, Error> {
+        Self::require_admin(&env)?;
+        Self::require_scope_active(&env, PauseScope::VaultCreation)?;
+        Self::require_valid_batch(&batch_data)?;
+
         let mut vault_ids = Vec::new(&env);
         let initial_count: u64 = env
             .storage()
@@ -1438,7 +3323,7 @@ fn calculate_time_vested_amount(env: &Env, vault: &Vault) -> i128 {
             .get(&DataKey::AdminBalance)
             .unwrap_or(0);
         if admin_balance < total_amount {
-            panic!("Insufficient admin balance for batch");
+            return Err(Error::InsufficientAdminBalance);
         }
         admin_balance -= total_amount;
         env.storage()
@@ -1450,59 +3335,55 @@ fn calculate_time_vested_amount(env: &Env, vault: &Vault) -> i128 {
             let vault_id = initial_count + i as u64 + 1;
             let start_time: u64 = batch_data.start_times.get(i).unwrap();
             let end_time: u64 = batch_data.end_times.get(i).unwrap();
-            Self::require_valid_duration(start_time, end_time);
-
-            let owner = batch_data.recipients.get(i).unwrap();
-            let amount = batch_data.amounts.get(i).unwrap();
-            let start_time = batch_data.start_times.get(i).unwrap();
-            let end_time = batch_data.end_times.get(i).unwrap();
-            let keeper_fee = batch_data.keeper_fees.get(i).unwrap();
-            let step_duration = batch_data.step_durations.get(i).unwrap_or(0);
-            let is_transferable = false;
-            let is_irrevocable = false;
+            Self::require_valid_duration(start_time, end_time)?;
 
             let vault = Vault {
-                owner: owner.clone(),
-                title: String::from_slice(&env, ""),
+                total_amount: batch_data.amounts.get(i).unwrap(),
+                released_amount: 0,
+                keeper_fee: batch_data.keeper_fees.get(i).unwrap(),
+                staked_amount: 0,
                 owner: batch_data.recipients.get(i).unwrap(),
                 delegate: None,
-                total_amount: amount,
-                released_amount: 0,
+                title: String::from_str(&env, ""),
                 start_time,
                 end_time,
-                keeper_fee,
-                keeper_fee: batch_data.keeper_fees.get(i).unwrap(),
-                title: String::from_slice(&env, ""),
-                is_initialized: false, // Lazy initialization
-                is_irrevocable: false, // Default to revocable for batch operations
-                is_initialized: false,
-                is_irrevocable: false,
                 creation_time: now,
-                step_duration,
-                is_initialized: false, // Lazy initialization
-                is_irrevocable,
-                is_transferable,
-                staked_amount: 0,
+                step_duration: batch_data.step_durations.get(i).unwrap_or(0),
+                is_initialized: true,
+                is_irrevocable: false,
+                is_transferable: false,
                 is_frozen: false,
-                title: String::from_slice(&env, ""),
             };
 
-            env.storage()
-                .instance()
-                .set(&DataKey::VaultData(vault_id), &vault);
+            Self::set_persistent(&env, &DataKey::VaultData(vault_id), &vault);
+
+            let mut user_vaults: Vec<u64> =
+                Self::get_persistent(&env, &DataKey::UserVaults(vault.owner.clone()))
+                    .unwrap_or(Vec::new(&env));
+            user_vaults.push_back(vault_id);
+            Self::set_persistent(
+                &env,
+                &DataKey::UserVaults(vault.owner.clone()),
+                &user_vaults,
+            );
+
+            Self::extend_vault_entries(&env, vault_id, &vault);
             vault_ids.push_back(vault_id);
 
-            let cliff_duration = start_time.saturating_sub(now);
-            let vault_created = VaultCreated {
-                vault_id,
-                beneficiary: vault.owner.clone(),
-                total_amount: vault.total_amount,
-                cliff_duration,
-                start_time,
-                title: String::from_slice(&env, ""),
-            };
-            env.events()
-                .publish((Symbol::new(&env, "VaultCreated"), vault_id), vault_created);
+            Self::record_admin_action(
+                &env,
+                AdminAction::VaultCreated,
+                Some(vault_id),
+                vec![
+                    &env,
+                    vault.owner.into_val(&env),
+                    vault.total_amount.into_val(&env),
+                    start_time.into_val(&env),
+                    end_time.into_val(&env),
+                ],
+            )?;
+
+            Self::emit_vault_created(&env, vault_id, &vault);
         }
 
         let mut total_shares: i128 = env
@@ -1520,201 +3401,463 @@ fn calculate_time_vested_amount(env: &Env, vault: &Vault) -> i128 {
             .instance()
             .set(&DataKey::VaultCount, &final_count);
 
-        vault_ids
+        Self::record_cohort(&env, &vault_ids);
+
+        Ok(vault_ids)
+    }
+
+    // Every batch creation call forms a cohort; IDs start at 1
+    fn record_cohort(env: &Env, vault_ids: &Vec<u64>) -> u64 {
+        let cohort_id: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::CohortCount)
+            .unwrap_or(0)
+            + 1;
+        env.storage()
+            .instance()
+            .set(&DataKey::CohortCount, &cohort_id);
+
+        Self::set_persistent(env, &DataKey::CohortVaults(cohort_id), vault_ids);
+        for vault_id in vault_ids.iter() {
+            Self::set_persistent(env, &DataKey::VaultCohort(vault_id), &cohort_id);
+        }
+
+        CohortCreated {
+            cohort_id,
+            vault_ids: vault_ids.clone(),
+            timestamp: env.ledger().timestamp(),
+            version: EVENT_VERSION,
+        }
+        .publish(env);
+
+        cohort_id
+    }
+
+    pub fn get_cohort_count(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::CohortCount)
+            .unwrap_or(0)
+    }
+
+    pub fn get_vault_cohort(env: Env, vault_id: u64) -> Option<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::VaultCohort(vault_id))
+    }
+
+    // Page through the vault IDs created in one cohort
+    pub fn get_cohort_vaults(
+        env: Env,
+        cohort_id: u64,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<u64>, Error> {
+        let vault_ids = Self::require_cohort(&env, cohort_id)?;
+        let end = vault_ids
+            .len()
+            .min(offset.saturating_add(limit.min(MAX_PAGE_SIZE)));
+        if offset >= end {
+            return Ok(Vec::new(&env));
+        }
+        Ok(vault_ids.slice(offset..end))
+    }
+
+    fn require_cohort(env: &Env, cohort_id: u64) -> Result<Vec<u64>, Error> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::CohortVaults(cohort_id))
+            .ok_or(Error::CohortNotFound)
+    }
+
+    // Admin-only: freeze every member of a cohort that is not already frozen.
+    // Returns the number of vaults frozen.
+    pub fn freeze_cohort(
+        env: Env,
+        cohort_id: u64,
+        reason_code: u32,
+        reference_hash: Option<BytesN<32>>,
+        expires_at: Option<u64>,
+    ) -> Result<u32, Error> {
+        Self::require_admin(&env)?;
+
+        let mut affected = 0u32;
+        for vault_id in Self::require_cohort(&env, cohort_id)?.iter() {
+            let vault = match Self::try_load_vault(&env, vault_id)? {
+                Some(vault) => vault,
+                None => continue, // archived
+            };
+            if Self::is_frozen_now(&env, vault_id, &vault) {
+                continue;
+            }
+            Self::internal_freeze(
+                &env,
+                vault_id,
+                reason_code,
+                reference_hash.clone(),
+                expires_at,
+            )?;
+            affected += 1;
+        }
+
+        CohortFrozen {
+            cohort_id,
+            affected,
+            reason_code,
+            version: EVENT_VERSION,
+        }
+        .publish(&env);
+        Ok(affected)
+    }
+
+    // Admin-only: mark every revocable member of a cohort irrevocable.
+    // Returns the number of vaults changed.
+    pub fn mark_cohort_irrevocable(env: Env, cohort_id: u64) -> Result<u32, Error> {
+        Self::require_admin(&env)?;
+
+        let mut affected = 0u32;
+        for vault_id in Self::require_cohort(&env, cohort_id)?.iter() {
+            match Self::try_load_vault(&env, vault_id)? {
+                Some(vault) if !vault.is_irrevocable => {
+                    Self::internal_mark_irrevocable(&env, vault_id)?;
+                    affected += 1;
+                }
+                _ => {}
+            }
+        }
+
+        CohortIrrevocableMarked {
+            cohort_id,
+            affected,
+            version: EVENT_VERSION,
+        }
+        .publish(&env);
+        Ok(affected)
+    }
+
+    // Admin-only: unlock a milestone on every member that has it configured and still locked.
+    // Returns the number of vaults changed.
+    pub fn unlock_cohort_milestone(
+        env: Env,
+        cohort_id: u64,
+        milestone_id: u64,
+    ) -> Result<u32, Error> {
+        Self::require_admin(&env)?;
+
+        let mut affected = 0u32;
+        for vault_id in Self::require_cohort(&env, cohort_id)?.iter() {
+            if !env
+                .storage()
+                .persistent()
+                .has(&DataKey::VaultData(vault_id))
+            {
+                continue;
+            }
+            let milestones: Vec<Milestone> =
+                Self::get_persistent(&env, &DataKey::VaultMilestones(vault_id))
+                    .unwrap_or(Vec::new(&env));
+            if milestones
+                .iter()
+                .any(|m| m.id == milestone_id && !m.is_unlocked)
+            {
+                Self::internal_unlock_milestone(&env, vault_id, milestone_id)?;
+                affected += 1;
+            }
+        }
+
+        CohortMilestoneUnlocked {
+            cohort_id,
+            milestone_id,
+            affected,
+            version: EVENT_VERSION,
+        }
+        .publish(&env);
+        Ok(affected)
     }
 
-    // Batch create vaults with full initialization
-    pub fn batch_create_vaults_full(env: Env, batch_data: BatchCreateData) -> Vec<u64> {
-        Self::require_admin(&env);
+    // Admin-only: revoke the unreleased balance of every revocable member of a cohort.
+    // Irrevocable and exhausted vaults are skipped. Returns the total returned to the admin balance.
+    pub fn revoke_cohort(env: Env, cohort_id: u64) -> Result<i128, Error> {
+        Self::require_admin(&env)?;
 
-        let mut vault_ids = Vec::new(&env);
-        let initial_count: u64 = env
-            .storage()
-            .instance()
-            .get(&DataKey::VaultCount)
-            .unwrap_or(0);
+        let mut total_returned: i128 = 0;
+        for vault_id in Self::require_cohort(&env, cohort_id)?.iter() {
+            let vault = match Self::try_load_vault(&env, vault_id)? {
+                Some(vault) => vault,
+                None => continue,
+            };
+            if vault.is_irrevocable || vault.released_amount >= vault.total_amount {
+                continue;
+            }
+
+            let returned = Self::internal_revoke_full(&env, vault_id)?;
+            total_returned += returned;
+
+            Self::record_admin_action(
+                &env,
+                AdminAction::TokensRevoked,
+                Some(vault_id),
+                vec![&env, returned.into_val(&env)],
+            )?;
+        }
 
-        let total_amount: i128 = batch_data.amounts.iter().sum();
         let mut admin_balance: i128 = env
             .storage()
             .instance()
             .get(&DataKey::AdminBalance)
             .unwrap_or(0);
-        if admin_balance < total_amount {
-            panic!("Insufficient admin balance for batch");
-        }
-        admin_balance -= total_amount;
+        admin_balance += total_returned;
         env.storage()
             .instance()
             .set(&DataKey::AdminBalance, &admin_balance);
 
-        let now = env.ledger().timestamp();
-        for i in 0..batch_data.recipients.len() {
-            let vault_id = initial_count + i as u64 + 1;
-            let start_time: u64 = batch_data.start_times.get(i).unwrap();
-            let end_time: u64 = batch_data.end_times.get(i).unwrap();
-            Self::require_valid_duration(start_time, end_time);
+        CohortRevoked {
+            cohort_id,
+            total_returned,
+            timestamp: env.ledger().timestamp(),
+            version: EVENT_VERSION,
+        }
+        .publish(&env);
+        Ok(total_returned)
+    }
 
-            let vault = Vault {
-                title: String::from_slice(&env, ""),
-                owner: batch_data.recipients.get(i).unwrap(),
-                delegate: None,
-                total_amount: batch_data.amounts.get(i).unwrap(),
-                released_amount: 0,
-                start_time,
-                end_time,
-                keeper_fee: batch_data.keeper_fees.get(i).unwrap(),
-                title: String::from_slice(&env, ""),
-                is_initialized: true,
-                is_irrevocable: false,
-                creation_time: now,
-                is_transferable: false,
-                step_duration: batch_data.step_durations.get(i).unwrap_or(0),
-                staked_amount: 0,
-                is_frozen: false,
-                title: String::from_slice(&env, ""),
-            };
+    // Get vault info (initializes if needed)
+    pub fn get_vault(env: Env, vault_id: u64) -> Result<Vault, Error> {
+        let vault: Vault = Self::load_vault(&env, vault_id)?;
 
-            env.storage()
-                .instance()
-                .set(&DataKey::VaultData(vault_id), &vault);
+        if !vault.is_initialized {
+            Self::initialize_vault_metadata(&env, vault_id)?;
+            Self::load_vault(&env, vault_id)
+        } else {
+            Ok(vault)
+        }
+    }
 
-            let mut user_vaults: Vec<u64> = env
-                .storage()
-                .instance()
-                .get(&DataKey::UserVaults(vault.owner.clone()))
-                .unwrap_or(Vec::new(&env));
-            user_vaults.push_back(vault_id);
-            env.storage()
-                .instance()
-                .set(&DataKey::UserVaults(vault.owner.clone()), &user_vaults);
+    // Permissionless: compact a fully released vault into an ArchivedVault summary,
+    // delete its full record and milestones, and drop it from the owner's index
+    pub fn archive_vault(env: Env, vault_id: u64) -> Result<ArchivedVault, Error> {
+        let vault: Vault = Self::load_vault(&env, vault_id)?;
 
-            vault_ids.push_back(vault_id);
+        if vault.released_amount < vault.total_amount {
+            return Err(Error::VaultNotFullyReleased);
+        }
+        if vault.staked_amount > 0 {
+            return Err(Error::VaultHasStake);
+        }
+        if Self::is_frozen_now(&env, vault_id, &vault) {
+            return Err(Error::VaultFrozen);
+        }
 
-            let cliff_duration = start_time.saturating_sub(now);
-            let vault_created = VaultCreated {
-                vault_id,
-                beneficiary: vault.owner.clone(),
-                total_amount: vault.total_amount,
-                cliff_duration,
-                start_time,
-                title: String::from_slice(&env, ""),
-            };
-            env.events()
-                .publish((Symbol::new(&env, "VaultCreated"), vault_id), vault_created);
+        let now = env.ledger().timestamp();
+        let archived = ArchivedVault {
+            owner: vault.owner.clone(),
+            total_amount: vault.total_amount,
+            title: vault.title.clone(),
+            start_time: vault.start_time,
+            end_time: vault.end_time,
+            creation_time: vault.creation_time,
+            archived_at: now,
+        };
+        Self::set_persistent(&env, &DataKey::ArchivedVault(vault_id), &archived);
+
+        let storage = env.storage().persistent();
+        storage.remove(&DataKey::VaultData(vault_id));
+        storage.remove(&DataKey::VaultMilestones(vault_id));
+        storage.remove(&DataKey::VaultKeeperFee(vault_id));
+        storage.remove(&DataKey::KeeperSponsored(vault_id));
+        storage.remove(&DataKey::AutoClaimPolicy(vault_id));
+        storage.remove(&DataKey::LastAutoClaim(vault_id));
+        storage.remove(&DataKey::ClaimSigner(vault_id));
+        storage.remove(&DataKey::ClaimNonce(vault_id));
+        storage.remove(&DataKey::VaultLiveUntil(vault_id));
+        for delegate in Self::delegates_of(&env, vault_id, &vault).iter() {
+            Self::drop_delegate(&env, &delegate, vault_id);
+        }
+        storage.remove(&DataKey::VaultDelegates(vault_id));
+        if vault.is_frozen {
+            // Lapsed freeze
+            Self::clear_freeze_record(&env, vault_id);
         }
 
-        let mut total_shares: i128 = env
-            .storage()
-            .instance()
-            .get(&DataKey::TotalShares)
-            .unwrap_or(0);
-        total_shares += total_amount;
-        env.storage()
-            .instance()
-            .set(&DataKey::TotalShares, &total_shares);
+        let mut user_vaults: Vec<u64> =
+            Self::get_persistent(&env, &DataKey::UserVaults(vault.owner.clone()))
+                .unwrap_or(Vec::new(&env));
+        if let Some(index) = user_vaults.first_index_of(vault_id) {
+            user_vaults.remove(index);
+            if user_vaults.is_empty() {
+                storage.remove(&DataKey::UserVaults(vault.owner.clone()));
+            } else {
+                Self::set_persistent(
+                    &env,
+                    &DataKey::UserVaults(vault.owner.clone()),
+                    &user_vaults,
+                );
+            }
+        }
 
-        let final_count = initial_count + batch_data.recipients.len() as u64;
-        env.storage()
-            .instance()
-            .set(&DataKey::VaultCount, &final_count);
+        VaultArchived {
+            vault_id,
+            owner: vault.owner,
+            total_amount: vault.total_amount,
+            released_amount: vault.released_amount,
+            title: vault.title,
+            timestamp: now,
+            version: EVENT_VERSION,
+        }
+        .publish(&env);
 
-        vault_ids
+        Ok(archived)
     }
 
-    // Get vault info (initializes if needed)
-    pub fn get_vault(env: Env, vault_id: u64) -> Vault {
-        let vault: Vault = env
-            .storage()
-            .instance()
-            .get(&DataKey::VaultData(vault_id))
-            .unwrap_or_else(|| panic!("Vault not found"));
-
-        if !vault.is_initialized {
-            Self::initialize_vault_metadata(&env, vault_id);
-            env.storage()
-                .instance()
-                .get(&DataKey::VaultData(vault_id))
-                .unwrap()
-                .unwrap_or_else(|| panic!("Vault not found"))
-        } else {
-            vault
-        }
+    pub fn get_archived_vault(env: Env, vault_id: u64) -> Option<ArchivedVault> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ArchivedVault(vault_id))
     }
 
     // Get user vaults (initializes all if needed)
-    pub fn get_user_vaults(env: Env, user: Address) -> Vec<u64> {
-        let vault_ids: Vec<u64> = env
-            .storage()
-            .instance()
-            .get(&DataKey::UserVaults(user.clone()))
-            .get(&DataKey::UserVaults(user))
-            .unwrap_or(Vec::new(&env));
+    pub fn get_user_vaults(env: Env, user: Address) -> Result<Vec<u64>, Error> {
+        let vault_ids: Vec<u64> =
+            Self::get_persistent(&env, &DataKey::UserVaults(user)).unwrap_or(Vec::new(&env));
 
         for vault_id in vault_ids.iter() {
-            let vault: Vault = env
-                .storage()
-                .instance()
-                .get(&DataKey::VaultData(vault_id))
-                .unwrap_or_else(|| panic!("Vault not found"));
+            let vault: Vault = Self::load_vault(&env, vault_id)?;
 
             if !vault.is_initialized {
-                Self::initialize_vault_metadata(&env, vault_id);
+                Self::initialize_vault_metadata(&env, vault_id)?;
             }
         }
 
-        vault_ids
+        Ok(vault_ids)
     }
 
-    // Admin-only: Revoke tokens from a vault and return them to admin
-    // Revoke tokens from a vault and return them to admin
-    // Internal helper: revoke full unreleased amount from a vault and emit event.
-    // Does NOT update admin balance — caller is responsible for a single aggregated transfer.
-    fn internal_revoke_full(env: &Env, vault_id: u64) -> i128 {
-    pub fn revoke_tokens(env: Env, vault_id: u64) -> i128 {
-        Self::require_admin(&env);
+    // Page through vault IDs a user owns
+    pub fn get_user_vaults_page(env: Env, user: Address, offset: u32, limit: u32) -> Vec<u64> {
+        let vault_ids: Vec<u64> =
+            Self::get_persistent(&env, &DataKey::UserVaults(user)).unwrap_or(Vec::new(&env));
+        let end = vault_ids
+            .len()
+            .min(offset.saturating_add(limit.min(MAX_PAGE_SIZE)));
+        if offset >= end {
+            return Vec::new(&env);
+        }
+        vault_ids.slice(offset..end)
+    }
+
+    pub fn get_user_vault_count(env: Env, user: Address) -> u32 {
+        Self::get_persistent::<Vec<u64>>(&env, &DataKey::UserVaults(user))
+            .map(|ids| ids.len())
+            .unwrap_or(0)
+    }
 
-        let mut vault: Vault = env
+    // Enumerate vaults with ID greater than `cursor` that match `filter`.
+    // At most MAX_LIST_SCAN IDs are inspected per call; pass `next_cursor` back to continue.
+    pub fn list_vaults(
+        env: Env,
+        cursor: u64,
+        limit: u32,
+        filter: VaultFilter,
+    ) -> Result<VaultPage, Error> {
+        let count: u64 = env
             .storage()
             .instance()
-            .get(&DataKey::VaultData(vault_id))
-            .unwrap_or_else(|| panic!("Vault not found"));
+            .get(&DataKey::VaultCount)
+            .unwrap_or(0);
+        let limit = limit.min(MAX_PAGE_SIZE);
+        let last_scannable = cursor.saturating_add(MAX_LIST_SCAN as u64).min(count);
+
+        let mut vault_ids = Vec::new(&env);
+        let mut id = cursor;
+        while id < last_scannable && vault_ids.len() < limit {
+            id += 1;
+            let vault: Vault = match Self::try_load_vault(&env, id)? {
+                Some(vault) => vault,
+                None => continue,
+            };
+            if Self::vault_matches(&env, id, &vault, &filter) {
+                vault_ids.push_back(id);
+            }
+        }
+
+        Ok(VaultPage {
+            vault_ids,
+            next_cursor: if id < count { Some(id) } else { None },
+        })
+    }
+
+    fn vault_matches(env: &Env, vault_id: u64, vault: &Vault, filter: &VaultFilter) -> bool {
+        if let Some(owner) = &filter.owner {
+            if vault.owner != *owner {
+                return false;
+            }
+        }
+        if let Some(frozen) = filter.frozen {
+            if Self::is_frozen_now(env, vault_id, vault) != frozen {
+                return false;
+            }
+        }
+        if let Some(irrevocable) = filter.irrevocable {
+            if vault.is_irrevocable != irrevocable {
+                return false;
+            }
+        }
+        if let Some(fully_released) = filter.fully_released {
+            if (vault.released_amount >= vault.total_amount) != fully_released {
+                return false;
+            }
+        }
+        true
+    }
+
+    // Internal helper: revoke full unreleased amount from a vault and emit event.
+    // Does NOT update admin balance — caller is responsible for a single aggregated transfer.
+    fn internal_revoke_full(env: &Env, vault_id: u64) -> Result<i128, Error> {
+        Self::require_vault_counted(env, vault_id)?;
+        let mut vault: Vault = Self::load_vault(env, vault_id)?;
 
         if vault.is_irrevocable {
-            panic!("Vault is irrevocable");
+            return Err(Error::VaultIrrevocable);
         }
 
         let unreleased_amount = vault.total_amount - vault.released_amount;
         if unreleased_amount <= 0 {
-        let returned = vault.total_amount - vault.released_amount;
-        if returned <= 0 {
-            panic!("No tokens available to revoke");
+            return Err(Error::NothingToRevoke);
         }
 
         vault.released_amount = vault.total_amount;
-        env.storage().instance().set(&DataKey::VaultData(vault_id), &vault);
+        Self::add_to_total(env, DataKey::TotalRevoked, unreleased_amount);
+        Self::set_persistent(env, &DataKey::VaultData(vault_id), &vault);
 
         let timestamp = env.ledger().timestamp();
-        env.events().publish(
-            (Symbol::new(env, "TokensRevoked"), vault_id),
-            (unreleased_amount, timestamp),
-        );
+        TokensRevoked {
+            vault_id,
+            beneficiary: vault.owner.clone(),
+            amount: unreleased_amount,
+            released_total: vault.released_amount,
+            timestamp,
+            version: EVENT_VERSION,
+        }
+        .publish(env);
 
-        let mut total_shares: i128 = env.storage().instance().get(&DataKey::TotalShares).unwrap_or(0);
+        let mut total_shares: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::TotalShares)
+            .unwrap_or(0);
         total_shares -= unreleased_amount;
-        env.storage().instance().set(&DataKey::TotalShares, &total_shares);
-        unreleased_amount
-    }
+        env.storage()
+            .instance()
+            .set(&DataKey::TotalShares, &total_shares);
 
+        Ok(unreleased_amount)
+    }
 
     // Admin-only: Revoke tokens from a vault and return them to admin
-    pub fn revoke_tokens(env: Env, vault_id: u64) -> i128 {
-        Self::require_admin(&env);
+    pub fn revoke_tokens(env: Env, vault_id: u64) -> Result<i128, Error> {
+        Self::require_admin(&env)?;
 
-        let returned = Self::internal_revoke_full(&env, vault_id);
-        env.storage()
-            .instance()
-            .set(&DataKey::VaultData(vault_id), &vault);
+        let returned = Self::internal_revoke_full(&env, vault_id)?;
 
         let mut admin_balance: i128 = env
             .storage()
@@ -1726,37 +3869,21 @@ fn calculate_time_vested_amount(env: &Env, vault: &Vault) -> i128 {
             .instance()
             .set(&DataKey::AdminBalance, &admin_balance);
 
-        let mut total_shares: i128 = env
-            .storage()
-            .instance()
-            .get(&DataKey::TotalShares)
-            .unwrap_or(0);
-        total_shares -= returned;
-        env.storage()
-            .instance()
-            .set(&DataKey::TotalShares, &total_shares);
-
-        let timestamp = env.ledger().timestamp();
-        env.events().publish(
-            (Symbol::new(&env, "TokensRevoked"), vault_id),
-            (unreleased_amount, timestamp),
-        );
+        Self::record_admin_action(
+            &env,
+            AdminAction::TokensRevoked,
+            Some(vault_id),
+            vec![&env, returned.into_val(&env)],
+        )?;
 
-        unreleased_amount
-        returned
+        Ok(returned)
     }
 
     // Revoke a specific amount of tokens from a vault and return them to admin
-    pub fn revoke_partial(env: Env, vault_id: u64, amount: i128) -> i128 {
-        Self::require_admin(&env);
-
-        let mut vault: Vault = env
-            .storage()
-            .instance()
-            .get(&DataKey::VaultData(vault_id))
-            .unwrap_or_else(|| panic!("Vault not found"));
+    pub fn revoke_partial(env: Env, vault_id: u64, amount: i128) -> Result<i128, Error> {
+        Self::require_admin(&env)?;
 
-        let returned = Self::internal_revoke_partial(&env, vault_id, amount);
+        let returned = Self::internal_revoke_partial(&env, vault_id, amount)?;
 
         // Single admin balance update for this call
         let mut admin_balance: i128 = env
@@ -1769,53 +3896,37 @@ fn calculate_time_vested_amount(env: &Env, vault: &Vault) -> i128 {
             .instance()
             .set(&DataKey::AdminBalance, &admin_balance);
 
-        returned
+        Self::record_admin_action(
+            &env,
+            AdminAction::TokensRevoked,
+            Some(vault_id),
+            vec![&env, returned.into_val(&env)],
+        )?;
+
+        Ok(returned)
     }
 
     // Internal helper: revoke a specific amount from a vault and emit event.
     // Does NOT update admin balance — caller is responsible for a single aggregated transfer.
-    fn internal_revoke_partial(env: &Env, vault_id: u64, amount: i128) -> i128 {
-        let mut vault: Vault = env
-            .storage()
-            .instance()
-            .get(&DataKey::VaultData(vault_id))
-            .unwrap_or_else(|| panic!("Vault not found"));
-
-        let mut vault: Vault = env
-            .storage()
-            .instance()
-            .get(&DataKey::VaultData(vault_id))
-            .unwrap_or_else(|| panic!("Vault not found"));
+    fn internal_revoke_partial(env: &Env, vault_id: u64, amount: i128) -> Result<i128, Error> {
+        Self::require_vault_counted(env, vault_id)?;
+        let mut vault: Vault = Self::load_vault(env, vault_id)?;
 
         if vault.is_irrevocable {
-            panic!("Vault is irrevocable");
+            return Err(Error::VaultIrrevocable);
         }
 
         let unvested_balance = vault.total_amount - vault.released_amount;
         if amount <= 0 {
-            panic!("Amount to revoke must be positive");
+            return Err(Error::InvalidAmount);
         }
         if amount > unvested_balance {
-            panic!("Amount exceeds unvested balance");
+            return Err(Error::AmountExceedsUnvested);
         }
 
         vault.released_amount += amount;
-        env.storage()
-            .instance()
-            .set(&DataKey::VaultData(vault_id), &vault);
-
-        let timestamp = env.ledger().timestamp();
-        env.events().publish(
-            (Symbol::new(env, "TokensRevoked"), vault_id),
-        let mut admin_balance: i128 = env
-            .storage()
-            .instance()
-            .get(&DataKey::AdminBalance)
-            .unwrap_or(0);
-        admin_balance += amount;
-        env.storage()
-            .instance()
-            .set(&DataKey::AdminBalance, &admin_balance);
+        Self::add_to_total(env, DataKey::TotalRevoked, amount);
+        Self::set_persistent(env, &DataKey::VaultData(vault_id), &vault);
 
         let mut total_shares: i128 = env
             .storage()
@@ -1828,51 +3939,29 @@ fn calculate_time_vested_amount(env: &Env, vault: &Vault) -> i128 {
             .set(&DataKey::TotalShares, &total_shares);
 
         let timestamp = env.ledger().timestamp();
-        env.events().publish(
-            (Symbol::new(&env, "TokensRevoked"), vault_id),
-            (amount, timestamp),
-        );
-
-        let mut total_shares: i128 = env.storage().instance().get(&DataKey::TotalShares).unwrap_or(0);
-        total_shares -= amount;
-        env.storage().instance().set(&DataKey::TotalShares, &total_shares);
-
-        let timestamp = env.ledger().timestamp();
-        env.events().publish((Symbol::new(&env, "TokensRevoked"), vault_id), (amount, timestamp));
+        TokensRevoked {
+            vault_id,
+            beneficiary: vault.owner.clone(),
+            amount,
+            released_total: vault.released_amount,
+            timestamp,
+            version: EVENT_VERSION,
+        }
+        .publish(env);
 
-        amount
+        Ok(amount)
     }
 
-    pub fn batch_revoke(env: Env, vault_ids: Vec<u64>) -> i128 {
-        Self::require_admin(&env);
+    pub fn batch_revoke(env: Env, vault_ids: Vec<u64>) -> Result<i128, Error> {
+        Self::require_admin(&env)?;
 
         let mut total_returned: i128 = 0;
-        let mut total_shares_change: i128 = 0;
-        
-        for id in vault_ids.iter() {
-            let vault: Vault = env.storage().instance().get(&DataKey::VaultData(id)).unwrap_or_else(|| panic!("Vault not found"));
-            
-            if vault.is_irrevocable { panic!("Vault is irrevocable"); }
-            
-            let unreleased_amount = vault.total_amount - vault.released_amount;
-            if unreleased_amount > 0 {
-                total_returned += unreleased_amount;
-                total_shares_change += unreleased_amount;
-                
-                // Update vault to mark all tokens as released
-                let mut updated_vault = vault;
-                updated_vault.released_amount = vault.total_amount;
-                env.storage().instance().set(&DataKey::VaultData(id), &updated_vault);
-            }
         for vault_id in vault_ids.iter() {
-            let mut vault: Vault = env
-                .storage()
-                .instance()
-                .get(&DataKey::VaultData(vault_id))
-                .unwrap_or_else(|| panic!("Vault not found"));
+            Self::require_vault_counted(&env, vault_id)?;
+            let mut vault: Vault = Self::load_vault(&env, vault_id)?;
 
             if vault.is_irrevocable {
-                panic!("Vault is irrevocable");
+                return Err(Error::VaultIrrevocable);
             }
 
             let returned = vault.total_amount - vault.released_amount;
@@ -1881,18 +3970,30 @@ fn calculate_time_vested_amount(env: &Env, vault: &Vault) -> i128 {
             }
 
             vault.released_amount = vault.total_amount;
-            env.storage()
-                .instance()
-                .set(&DataKey::VaultData(vault_id), &vault);
+            Self::set_persistent(&env, &DataKey::VaultData(vault_id), &vault);
             total_returned += returned;
 
+            Self::record_admin_action(
+                &env,
+                AdminAction::TokensRevoked,
+                Some(vault_id),
+                vec![&env, returned.into_val(&env)],
+            )?;
+
             let timestamp = env.ledger().timestamp();
-            env.events().publish(
-                (Symbol::new(&env, "TokensRevoked"), vault_id),
-                (returned, timestamp),
-            );
+            TokensRevoked {
+                vault_id,
+                beneficiary: vault.owner.clone(),
+                amount: returned,
+                released_total: vault.released_amount,
+                timestamp,
+                version: EVENT_VERSION,
+            }
+            .publish(&env);
         }
 
+        Self::add_to_total(&env, DataKey::TotalRevoked, total_returned);
+
         let mut admin_balance: i128 = env
             .storage()
             .instance()
@@ -1902,11 +4003,6 @@ fn calculate_time_vested_amount(env: &Env, vault: &Vault) -> i128 {
         env.storage()
             .instance()
             .set(&DataKey::AdminBalance, &admin_balance);
-            
-        // Update total shares
-        let mut total_shares: i128 = env.storage().instance().get(&DataKey::TotalShares).unwrap_or(0);
-        total_shares -= total_shares_change;
-        env.storage().instance().set(&DataKey::TotalShares, &total_shares);
 
         let mut total_shares: i128 = env
             .storage()
@@ -1919,37 +4015,32 @@ fn calculate_time_vested_amount(env: &Env, vault: &Vault) -> i128 {
             .set(&DataKey::TotalShares, &total_shares);
 
         let timestamp = env.ledger().timestamp();
-        env.events().publish(
-            (Symbol::new(&env, "BatchRevoked"),),
-            (vault_ids.len(), total_returned, timestamp),
-        );
+        BatchRevoked {
+            vault_count: vault_ids.len(),
+            total_returned,
+            timestamp,
+            version: EVENT_VERSION,
+        }
+        .publish(&env);
 
-        total_returned
+        Ok(total_returned)
     }
 
     // Clawback a vault within the grace period (1 hour)
-    pub fn clawback_vault(env: Env, vault_id: u64) -> i128 {
-        Self::require_admin(&env);
+    pub fn clawback_vault(env: Env, vault_id: u64) -> Result<i128, Error> {
+        Self::require_admin(&env)?;
+        Self::require_vault_counted(&env, vault_id)?;
 
-        let mut vault: Vault = env
-            .storage()
-            .instance()
-            .get(&DataKey::VaultData(vault_id))
-            .unwrap_or_else(|| panic!("Vault not found"));
+        let mut vault: Vault = Self::load_vault(&env, vault_id)?;
 
         let now = env.ledger().timestamp();
         let grace_period = 3600u64;
 
         if now > vault.creation_time + grace_period {
-            panic!("Grace period expired");
-        }
-        if vault.released_amount > 0 {
-            panic!("Tokens already claimed");
+            return Err(Error::GracePeriodExpired);
         }
-
-
         if vault.released_amount > 0 {
-            panic!("Tokens already claimed");
+            return Err(Error::TokensAlreadyClaimed);
         }
 
         let mut admin_balance: i128 = env
@@ -1963,9 +4054,8 @@ fn calculate_time_vested_amount(env: &Env, vault: &Vault) -> i128 {
             .set(&DataKey::AdminBalance, &admin_balance);
 
         vault.released_amount = vault.total_amount;
-        env.storage()
-            .instance()
-            .set(&DataKey::VaultData(vault_id), &vault);
+        Self::add_to_total(&env, DataKey::TotalRevoked, vault.total_amount);
+        Self::set_persistent(&env, &DataKey::VaultData(vault_id), &vault);
 
         let mut total_shares: i128 = env
             .storage()
@@ -1977,38 +4067,45 @@ fn calculate_time_vested_amount(env: &Env, vault: &Vault) -> i128 {
             .instance()
             .set(&DataKey::TotalShares, &total_shares);
 
-        env.events().publish(
-            (Symbol::new(&env, "VaultClawedBack"), vault_id),
-            vault.total_amount,
-        );
+        Self::record_admin_action(
+            &env,
+            AdminAction::VaultClawedBack,
+            Some(vault_id),
+            vec![&env, vault.total_amount.into_val(&env)],
+        )?;
+
+        VaultClawedBack {
+            vault_id,
+            owner: vault.owner,
+            amount: vault.total_amount,
+            timestamp: env.ledger().timestamp(),
+            version: EVENT_VERSION,
+        }
+        .publish(&env);
 
-        vault.total_amount
+        Ok(vault.total_amount)
     }
 
     // Transfer vault ownership to another beneficiary (if transferable)
-    pub fn transfer_vault(env: Env, vault_id: u64, new_beneficiary: Address) {
-        let mut vault: Vault = env
-            .storage()
-            .instance()
-            .get(&DataKey::VaultData(vault_id))
-            .unwrap_or_else(|| panic!("Vault not found"));
+    pub fn transfer_vault(env: Env, vault_id: u64, new_beneficiary: Address) -> Result<(), Error> {
+        Self::require_scope_active(&env, PauseScope::Transfers)?;
+
+        let mut vault: Vault = Self::load_vault(&env, vault_id)?;
 
         if !vault.is_initialized {
-            panic!("Vault not initialized");
+            return Err(Error::VaultNotInitialized);
         }
         if !vault.is_transferable {
-            panic!("Vault is non-transferable");
+            return Err(Error::VaultNotTransferable);
         }
 
         vault.owner.require_auth();
 
         let old_owner = vault.owner.clone();
 
-        let old_user_vaults: Vec<u64> = env
-            .storage()
-            .instance()
-            .get(&DataKey::UserVaults(old_owner.clone()))
-            .unwrap_or(Vec::new(&env));
+        let old_user_vaults: Vec<u64> =
+            Self::get_persistent(&env, &DataKey::UserVaults(old_owner.clone()))
+                .unwrap_or(Vec::new(&env));
 
         let mut new_old_user_vaults = Vec::new(&env);
         for id in old_user_vaults.iter() {
@@ -2016,61 +4113,73 @@ fn calculate_time_vested_amount(env: &Env, vault: &Vault) -> i128 {
                 new_old_user_vaults.push_back(id);
             }
         }
-        env.storage()
-            .instance()
-            .set(&DataKey::UserVaults(old_owner.clone()), &new_old_user_vaults);
-        env.storage().instance().set(
+        Self::set_persistent(
+            &env,
             &DataKey::UserVaults(old_owner.clone()),
             &new_old_user_vaults,
         );
 
-        let mut new_user_vaults: Vec<u64> = env
-            .storage()
-            .instance()
-            .get(&DataKey::UserVaults(new_beneficiary.clone()))
-            .unwrap_or(Vec::new(&env));
+        let mut new_user_vaults: Vec<u64> =
+            Self::get_persistent(&env, &DataKey::UserVaults(new_beneficiary.clone()))
+                .unwrap_or(Vec::new(&env));
         new_user_vaults.push_back(vault_id);
-        env.storage()
-            .instance()
-            .set(&DataKey::UserVaults(new_beneficiary.clone()), &new_user_vaults);
-        env.storage().instance().set(
+        Self::set_persistent(
+            &env,
             &DataKey::UserVaults(new_beneficiary.clone()),
             &new_user_vaults,
         );
 
         vault.owner = new_beneficiary.clone();
-        vault.delegate = None;
+        // The previous owner's claim signer and auto-claim policy no longer
+        // speak for the vault
         env.storage()
-            .instance()
-            .set(&DataKey::VaultData(vault_id), &vault);
+            .persistent()
+            .remove(&DataKey::ClaimSigner(vault_id));
+        Self::clear_auto_claim_policy(&env, vault_id);
+        if let Some(old_delegate) = Self::clear_delegates(&env, vault_id, &mut vault) {
+            DelegateUpdated {
+                vault_id,
+                old_delegate: Some(old_delegate),
+                new_delegate: None,
+                version: EVENT_VERSION,
+            }
+            .publish(&env);
+        }
+        Self::set_persistent(&env, &DataKey::VaultData(vault_id), &vault);
+
+        BeneficiaryUpdated {
+            vault_id,
+            old_owner,
+            new_owner: new_beneficiary,
+            timestamp: env.ledger().timestamp(),
+            version: EVENT_VERSION,
+        }
+        .publish(&env);
 
-        env.events().publish(
-            (Symbol::new(&env, "BeneficiaryUpdated"), vault_id),
-            (old_owner, new_beneficiary),
-        );
+        Ok(())
     }
 
     // Rotate beneficiary key (security feature, allows self-transfer even if non-transferable)
-    pub fn rotate_beneficiary_key(env: Env, vault_id: u64, new_address: Address) {
-        let mut vault: Vault = env
-            .storage()
-            .instance()
-            .get(&DataKey::VaultData(vault_id))
-            .unwrap_or_else(|| panic!("Vault not found"));
+    pub fn rotate_beneficiary_key(
+        env: Env,
+        vault_id: u64,
+        new_address: Address,
+    ) -> Result<(), Error> {
+        Self::require_scope_active(&env, PauseScope::Transfers)?;
+
+        let mut vault: Vault = Self::load_vault(&env, vault_id)?;
 
         if !vault.is_initialized {
-            panic!("Vault not initialized");
+            return Err(Error::VaultNotInitialized);
         }
 
         vault.owner.require_auth();
 
         let old_owner = vault.owner.clone();
 
-        let old_user_vaults: Vec<u64> = env
-            .storage()
-            .instance()
-            .get(&DataKey::UserVaults(old_owner.clone()))
-            .unwrap_or(Vec::new(&env));
+        let old_user_vaults: Vec<u64> =
+            Self::get_persistent(&env, &DataKey::UserVaults(old_owner.clone()))
+                .unwrap_or(Vec::new(&env));
 
         let mut new_old_user_vaults = Vec::new(&env);
         for id in old_user_vaults.iter() {
@@ -2078,71 +4187,138 @@ fn calculate_time_vested_amount(env: &Env, vault: &Vault) -> i128 {
                 new_old_user_vaults.push_back(id);
             }
         }
-        env.storage()
-            .instance()
-            .set(&DataKey::UserVaults(old_owner.clone()), &new_old_user_vaults);
-        env.storage().instance().set(
+        Self::set_persistent(
+            &env,
             &DataKey::UserVaults(old_owner.clone()),
             &new_old_user_vaults,
-        );
-
-        let mut new_user_vaults: Vec<u64> = env
-            .storage()
-            .instance()
-            .get(&DataKey::UserVaults(new_address.clone()))
-            .unwrap_or(Vec::new(&env));
+        );
+
+        let mut new_user_vaults: Vec<u64> =
+            Self::get_persistent(&env, &DataKey::UserVaults(new_address.clone()))
+                .unwrap_or(Vec::new(&env));
         new_user_vaults.push_back(vault_id);
-        env.storage()
-            .instance()
-            .set(&DataKey::UserVaults(new_address.clone()), &new_user_vaults);
+        Self::set_persistent(
+            &env,
+            &DataKey::UserVaults(new_address.clone()),
+            &new_user_vaults,
+        );
 
         vault.owner = new_address.clone();
-        vault.delegate = None;
+        // The previous owner's claim signer and auto-claim policy no longer
+        // speak for the vault
         env.storage()
-            .instance()
-            .set(&DataKey::VaultData(vault_id), &vault);
+            .persistent()
+            .remove(&DataKey::ClaimSigner(vault_id));
+        Self::clear_auto_claim_policy(&env, vault_id);
+        if let Some(old_delegate) = Self::clear_delegates(&env, vault_id, &mut vault) {
+            DelegateUpdated {
+                vault_id,
+                old_delegate: Some(old_delegate),
+                new_delegate: None,
+                version: EVENT_VERSION,
+            }
+            .publish(&env);
+        }
+        Self::set_persistent(&env, &DataKey::VaultData(vault_id), &vault);
+
+        BeneficiaryRotated {
+            vault_id,
+            old_owner,
+            new_owner: new_address,
+            timestamp: env.ledger().timestamp(),
+            version: EVENT_VERSION,
+        }
+        .publish(&env);
 
-        env.events().publish(
-            (Symbol::new(&env, "BeneficiaryRotated"), vault_id),
-            (old_owner, new_address),
-        );
+        Ok(())
     }
 
     // Set the whitelisted staking contract address
-    pub fn set_staking_contract(env: Env, contract: Address) {
-        Self::require_admin(&env);
+    pub fn set_staking_contract(env: Env, contract: Address) -> Result<(), Error> {
+        Self::require_admin(&env)?;
         env.storage()
             .instance()
             .set(&Symbol::new(&env, "StakingContract"), &contract);
+
+        Ok(())
     }
 
     // Stake unvested tokens to the whitelisted staking contract
-    pub fn stake_tokens(env: Env, vault_id: u64, amount: i128, validator: Address) {
-        let mut vault: Vault = env
-            .storage()
-            .instance()
-            .get(&DataKey::VaultData(vault_id))
-            .unwrap_or_else(|| panic!("Vault not found"));
+    pub fn stake_tokens(
+        env: Env,
+        vault_id: u64,
+        amount: i128,
+        validator: Address,
+    ) -> Result<(), Error> {
+        Self::internal_stake(env, vault_id, amount, validator, StakePath::Owner)
+    }
+
+    // Stake on the owner's behalf as the primary delegate; the delegate's
+    // permissions must allow staking
+    pub fn stake_as_delegate(
+        env: Env,
+        vault_id: u64,
+        amount: i128,
+        validator: Address,
+    ) -> Result<(), Error> {
+        Self::internal_stake(env, vault_id, amount, validator, StakePath::Delegate(None))
+    }
+
+    // Same as `stake_as_delegate`, acting as any one of the vault's delegates
+    pub fn delegate_stake(
+        env: Env,
+        vault_id: u64,
+        delegate: Address,
+        amount: i128,
+        validator: Address,
+    ) -> Result<(), Error> {
+        Self::internal_stake(
+            env,
+            vault_id,
+            amount,
+            validator,
+            StakePath::Delegate(Some(delegate)),
+        )
+    }
+
+    fn internal_stake(
+        env: Env,
+        vault_id: u64,
+        amount: i128,
+        validator: Address,
+        path: StakePath,
+    ) -> Result<(), Error> {
+        Self::require_scope_active(&env, PauseScope::Staking)?;
+        Self::require_vault_counted(&env, vault_id)?;
+
+        let mut vault: Vault = Self::load_vault(&env, vault_id)?;
 
         if !vault.is_initialized {
-            panic!("Vault not initialized");
+            return Err(Error::VaultNotInitialized);
         }
 
-        vault.owner.require_auth();
+        match path {
+            StakePath::Owner => vault.owner.require_auth(),
+            StakePath::Delegate(delegate) => {
+                let delegate = Self::resolve_delegate(&env, vault_id, &vault, delegate)?;
+                delegate.require_auth();
+                Self::require_delegate_permitted(&env, vault_id, &delegate, true)?;
+            }
+        }
 
         let available = vault.total_amount - vault.released_amount - vault.staked_amount;
         if amount <= 0 {
-            panic!("Amount must be positive");
+            return Err(Error::InvalidAmount);
         }
         if amount > available {
-            panic!("Insufficient funds to stake");
+            return Err(Error::InsufficientStakeableFunds);
         }
 
         let staking_contract: Address = env
             .storage()
             .instance()
             .get(&Symbol::new(&env, "StakingContract"))
-            .expect("Staking contract not set");
+            .ok_or(Error::StakingContractNotSet)?;
 
         let args = vec![
             &env,
@@ -2164,83 +4340,91 @@ fn calculate_time_vested_amount(env: &Env, vault: &Vault) -> i128 {
             .instance()
             .set(&DataKey::TotalStaked, &total_staked);
 
-        env.storage()
-            .instance()
-            .set(&DataKey::VaultData(vault_id), &vault);
+        Self::set_persistent(&env, &DataKey::VaultData(vault_id), &vault);
+
+        TokensStaked {
+            vault_id,
+            validator,
+            amount,
+            staked_total: vault.staked_amount,
+            version: EVENT_VERSION,
+        }
+        .publish(&env);
+
+        Ok(())
     }
 
     // Mark a vault as irrevocable to prevent admin withdrawal
-    pub fn mark_irrevocable(env: Env, vault_id: u64) {
-        Self::require_admin(&env);
+    pub fn mark_irrevocable(env: Env, vault_id: u64) -> Result<(), Error> {
+        Self::require_admin(&env)?;
+        Self::internal_mark_irrevocable(&env, vault_id)
+    }
 
-        let mut vault: Vault = env
-            .storage()
-            .instance()
-            .get(&DataKey::VaultData(vault_id))
-            .unwrap_or_else(|| panic!("Vault not found"));
+    fn internal_mark_irrevocable(env: &Env, vault_id: u64) -> Result<(), Error> {
+        let mut vault: Vault = Self::load_vault(env, vault_id)?;
 
         if vault.is_irrevocable {
-            panic!("Vault is already irrevocable");
+            return Err(Error::VaultAlreadyIrrevocable);
         }
 
         vault.is_irrevocable = true;
-        env.storage()
-            .instance()
-            .set(&DataKey::VaultData(vault_id), &vault);
+        Self::set_persistent(env, &DataKey::VaultData(vault_id), &vault);
+
+        Self::record_admin_action(
+            env,
+            AdminAction::IrrevocableMarked,
+            Some(vault_id),
+            vec![env],
+        )?;
 
         let timestamp = env.ledger().timestamp();
-        env.events().publish(
-            (Symbol::new(&env, "IrrevocableMarked"), vault_id),
+        IrrevocableMarked {
+            vault_id,
             timestamp,
-        );
+            version: EVENT_VERSION,
+        }
+        .publish(env);
+
+        Ok(())
     }
 
     // Check if a vault is irrevocable
-    pub fn is_vault_irrevocable(env: Env, vault_id: u64) -> bool {
-        let vault: Vault = env
-            .storage()
-            .instance()
-            .get(&DataKey::VaultData(vault_id))
-            .unwrap_or_else(|| panic!("Vault not found"));
+    pub fn is_vault_irrevocable(env: Env, vault_id: u64) -> Result<bool, Error> {
+        let vault: Vault = Self::load_vault(&env, vault_id)?;
 
-        vault.is_irrevocable
+        Ok(vault.is_irrevocable)
     }
 
     // Get contract state for invariant checking
     pub fn get_contract_state(env: Env) -> (i128, i128, i128) {
+        let totals = Self::get_accounting_totals(env.clone());
         let admin_balance: i128 = env
             .storage()
             .instance()
             .get(&DataKey::AdminBalance)
             .unwrap_or(0);
 
-        let vault_count: u64 = env
-            .storage()
-            .instance()
-            .get(&DataKey::VaultCount)
-            .unwrap_or(0);
-        let mut total_locked = 0i128;
-        let mut total_claimed = 0i128;
+        // Claimed here means everything no longer locked in a vault, revocations included
+        (
+            totals.locked,
+            totals.released + totals.revoked,
+            admin_balance,
+        )
+    }
 
-        for i in 1..=vault_count {
-            if let Some(vault) = env
-                .storage()
-                .instance()
-                .get::<DataKey, Vault>(&DataKey::VaultData(i))
-            {
-                total_locked += vault.total_amount - vault.released_amount;
-                total_claimed += vault.released_amount;
-            }
+    // Constant-time view of the running totals maintained by every mutating entry point
+    pub fn get_accounting_totals(env: Env) -> AccountingTotals {
+        let storage = env.storage().instance();
+        AccountingTotals {
+            locked: storage.get(&DataKey::TotalShares).unwrap_or(0),
+            released: storage.get(&DataKey::TotalReleased).unwrap_or(0),
+            revoked: storage.get(&DataKey::TotalRevoked).unwrap_or(0),
+            staked: storage.get(&DataKey::TotalStaked).unwrap_or(0),
         }
-
-        (total_locked, total_claimed, admin_balance)
     }
 
     // Check invariant: Total Locked + Admin Balance + Tokens Paid Out = Initial Supply
     // Tokens paid out = total_claimed minus any that were revoked (returned to admin_balance).
-    // Simplest correct form: total_locked + admin_balance <= initial_supply
-    // and total_locked + admin_balance + net_paid_out == initial_supply.
-    // We verify: admin_balance + total_locked == initial_supply - net_distributed
     // The safe checkable invariant: total_locked + admin_balance must never exceed initial_supply,
     // and (initial_supply - admin_balance - total_locked) must be non-negative (tokens claimed out).
     pub fn check_invariant(env: Env) -> bool {
@@ -2260,68 +4444,216 @@ fn calculate_time_vested_amount(env: &Env, vault: &Vault) -> i128 {
 
     // --- Auto-Claim Logic ---
 
-    // Calculate currently claimable tokens based on linear vesting
-    pub fn get_claimable_amount(env: Env, vault_id: u64) -> i128 {
-        let vault: Vault = env
-            .storage()
-            .instance()
-            .get(&DataKey::VaultData(vault_id))
-            .unwrap_or_else(|| panic!("Vault not found"));
-
-        let vested = Self::calculate_time_vested_amount(&env, &vault);
+    // Shares currently claimable: milestone-gated or time-vested, minus released
+    pub fn get_claimable_amount(env: Env, vault_id: u64) -> Result<i128, Error> {
+        let vault: Vault = Self::load_vault(&env, vault_id)?;
+        let unlocked = Self::unlocked_for(&env, vault_id, &vault)?;
+        Ok((unlocked - vault.released_amount).max(0))
+    }
 
-        if vested > vault.released_amount {
-            vested - vault.released_amount
-        } else {
-            0
-        }
+    // Auto-claim function that any keeper can call with its own auth.
+    // Claims everything unlocked: tokens go to the beneficiary, but the keeper
+    // earns the vault's fee. Returns the shares released to the beneficiary.
+    pub fn auto_claim(env: Env, vault_id: u64, keeper: Address) -> Result<i128, Error> {
+        keeper.require_auth();
+        let outcome = Self::execute_claim(
+            &env,
+            vault_id,
+            None,
+            ClaimPath::Keeper(keeper),
+            None,
+            None,
+            None,
+        )?;
+        Ok(outcome.shares)
     }
 
-    // Auto-claim function that anyone can call.
-    // Tokens go to beneficiary, but keeper earns a fee.
-    pub fn auto_claim(env: Env, vault_id: u64, keeper: Address) {
-        if Self::is_paused(env.clone()) {
-            panic!("Contract is paused - all withdrawals are disabled");
+    // Auto-claim a list of vaults in one call. Vaults that don't exist, aren't
+    // initialized, are frozen, have nothing claimable, can't have their
+    // sponsored fee paid or are blocked by their policy are skipped instead of
+    // aborting the batch; any other error aborts it. Each beneficiary is paid
+    // per vault, while the keeper's fees are paid in one transfer at the end.
+    // Returns one result per requested vault, in order.
+    pub fn batch_auto_claim(
+        env: Env,
+        vault_ids: Vec<u64>,
+        keeper: Address,
+    ) -> Result<Vec<AutoClaimResult>, Error> {
+        keeper.require_auth();
+        Self::require_not_deprecated(&env)?;
+        Self::require_scope_active(&env, PauseScope::Claims)?;
+        Self::require_scope_active(&env, PauseScope::Keeper)?;
+
+        let mut results: Vec<AutoClaimResult> = Vec::new(&env);
+        let mut fee_tokens: i128 = 0;
+        let mut claimed_count: u32 = 0;
+        for vault_id in vault_ids.iter() {
+            let claimed = Self::execute_claim(
+                &env,
+                vault_id,
+                None,
+                ClaimPath::Keeper(keeper.clone()),
+                None,
+                None,
+                Some(&mut fee_tokens),
+            );
+            let (status, outcome) = match claimed {
+                Ok(outcome) => (AutoClaimStatus::Claimed, Some(outcome)),
+                Err(Error::VaultNotFound) => (AutoClaimStatus::NotFound, None),
+                Err(Error::VaultNotInitialized) => (AutoClaimStatus::NotInitialized, None),
+                Err(Error::VaultFrozen) => (AutoClaimStatus::Frozen, None),
+                Err(Error::NothingToClaim) => (AutoClaimStatus::NothingToClaim, None),
+                Err(Error::KeeperPoolInsufficient) => (AutoClaimStatus::PoolExhausted, None),
+                Err(
+                    Error::AutoClaimDisabled
+                    | Error::KeeperNotPermitted
+                    | Error::AutoClaimTooSoon
+                    | Error::AutoClaimBelowMinimum,
+                ) => (AutoClaimStatus::PolicyBlocked, None),
+                Err(e) => return Err(e),
+            };
+            if outcome.is_some() {
+                claimed_count += 1;
+            }
+            results.push_back(AutoClaimResult {
+                vault_id,
+                status,
+                shares: outcome.as_ref().map_or(0, |o| o.shares),
+                amount: outcome.as_ref().map_or(0, |o| o.amount),
+                keeper_fee: outcome.as_ref().map_or(0, |o| o.keeper_fee),
+            });
         }
 
-        let mut vault: Vault = env
-            .storage()
-            .instance()
-            .get(&DataKey::VaultData(vault_id))
-            .unwrap_or_else(|| panic!("Vault not found"));
+        if fee_tokens > 0 {
+            Self::get_token_client(&env)?.transfer(
+                &env.current_contract_address(),
+                &keeper,
+                &fee_tokens,
+            );
+        }
 
-        // Check if vault is frozen
-        if vault.is_frozen {
-            panic!("Vault is frozen - claims are disabled");
+        BatchAutoClaimed {
+            keeper,
+            vault_count: vault_ids.len(),
+            claimed_count,
+            fee_amount: fee_tokens,
+            version: EVENT_VERSION,
         }
+        .publish(&env);
 
-        if !vault.is_initialized {
-            panic!("Vault not initialized");
+        Ok(results)
+    }
+
+    // Owner-only: set (or with None, clear) the limits `auto_claim` must
+    // respect for this vault. Without a policy any keeper may claim at any time.
+    pub fn set_auto_claim_policy(
+        env: Env,
+        vault_id: u64,
+        policy: Option<AutoClaimPolicy>,
+    ) -> Result<(), Error> {
+        let vault: Vault = Self::load_vault(&env, vault_id)?;
+        vault.owner.require_auth();
+
+        let key = DataKey::AutoClaimPolicy(vault_id);
+        match &policy {
+            Some(policy) if policy.min_claim < 0 => return Err(Error::InvalidAmount),
+            Some(policy) => Self::set_persistent(&env, &key, policy),
+            None => env.storage().persistent().remove(&key),
         }
 
-        let claimable = Self::get_claimable_amount(env.clone(), vault_id);
+        AutoClaimPolicySet {
+            vault_id,
+            policy,
+            version: EVENT_VERSION,
+        }
+        .publish(&env);
+        Ok(())
+    }
 
-        
-        // Ensure there's enough to cover the fee and something left for beneficiary
-        if claimable <= vault.keeper_fee {
-            panic!("Insufficient claimable tokens to cover fee");
+    // Drop the owner's policy when the vault changes hands. The last keeper
+    // claim stays, since it describes the vault rather than the owner.
+    fn clear_auto_claim_policy(env: &Env, vault_id: u64) {
+        let key = DataKey::AutoClaimPolicy(vault_id);
+        if !env.storage().persistent().has(&key) {
+            return;
+        }
+        env.storage().persistent().remove(&key);
+        AutoClaimPolicySet {
+            vault_id,
+            policy: None,
+            version: EVENT_VERSION,
         }
+        .publish(env);
+    }
 
-        let beneficiary_amount = claimable - vault.keeper_fee;
-        let keeper_fee = vault.keeper_fee;
+    pub fn get_auto_claim_policy(env: Env, vault_id: u64) -> Option<AutoClaimPolicy> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::AutoClaimPolicy(vault_id))
+    }
 
-        vault.released_amount += claimable;
-        env.storage().instance().set(&DataKey::VaultData(vault_id), &vault);
+    pub fn get_last_auto_claim(env: Env, vault_id: u64) -> Option<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::LastAutoClaim(vault_id))
+    }
 
-        // YIELD DISTRIBUTION - only vault-owned portion
-        let token_client = Self::get_token_client(&env);
-        let current_balance = token_client.balance(&env.current_contract_address());
+    fn require_auto_claim_allowed(
+        env: &Env,
+        vault_id: u64,
+        keeper: &Address,
+        shares: i128,
+    ) -> Result<(), Error> {
+        let policy: AutoClaimPolicy = match env
+            .storage()
+            .persistent()
+            .get(&DataKey::AutoClaimPolicy(vault_id))
+        {
+            Some(policy) => policy,
+            None => return Ok(()),
+        };
+        if !policy.enabled {
+            return Err(Error::AutoClaimDisabled);
+        }
+        if !policy.allowed_keepers.is_empty() && !policy.allowed_keepers.contains(keeper) {
+            return Err(Error::KeeperNotPermitted);
+        }
+        let last: Option<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::LastAutoClaim(vault_id));
+        if let Some(last) = last {
+            if env.ledger().timestamp() < last.saturating_add(policy.min_interval) {
+                return Err(Error::AutoClaimTooSoon);
+            }
+        }
+        if shares < policy.min_claim {
+            return Err(Error::AutoClaimBelowMinimum);
+        }
+        Ok(())
+    }
+
+    // Tokens `shares` are worth: the contract's balance, less `owed` and the
+    // admin balance, keeper pool and owed keeper fees, split across the
+    // liquid shares. One share is one token while nothing is liquid.
+    fn shares_to_tokens(env: &Env, shares: i128, owed: i128) -> Result<i128, Error> {
+        let token_client = Self::get_token_client(env)?;
+        let current_balance = token_client.balance(&env.current_contract_address()) - owed;
         let admin_balance: i128 = env
             .storage()
             .instance()
             .get(&DataKey::AdminBalance)
             .unwrap_or(0);
-
+        let pool: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::KeeperPool)
+            .unwrap_or(0);
+        let keeper_owed: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::KeeperOwed)
+            .unwrap_or(0);
         let total_shares: i128 = env
             .storage()
             .instance()
@@ -2332,73 +4664,367 @@ fn calculate_time_vested_amount(env: &Env, vault: &Vault) -> i128 {
             .instance()
             .get(&DataKey::TotalStaked)
             .unwrap_or(0);
-        let liquid_shares = total_shares - total_staked;
 
-        let vault_portion = (current_balance - admin_balance).max(0);
-
-        let beneficiary_tokens = if liquid_shares > 0 {
-            (beneficiary_amount * vault_portion) / liquid_shares
+        let liquid_shares = total_shares - total_staked;
+        let vault_portion = (current_balance - admin_balance - pool - keeper_owed).max(0);
+        Ok(if liquid_shares > 0 {
+            (shares * vault_portion) / liquid_shares
         } else {
-            beneficiary_amount
+            shares
+        })
+    }
+
+    // Keeper fee in shares for auto-claiming `shares` from a vault. The first
+    // of these applies: the vault's config, its fixed `keeper_fee`, the global
+    // default, no fee. Whichever applies is waived when it would take the whole
+    // claim, except on `sponsored` vaults, whose fee does not come out of it.
+    fn keeper_fee_for(
+        env: &Env,
+        vault_id: u64,
+        vault: &Vault,
+        shares: i128,
+        sponsored: bool,
+    ) -> Result<i128, Error> {
+        let from_config = |config: KeeperFeeConfig| {
+            (shares * config.bps as i128 / MAX_KEEPER_FEE_BPS as i128)
+                .clamp(config.min_fee, config.max_fee)
         };
-        let keeper_tokens = if liquid_shares > 0 {
-            (keeper_fee * vault_portion) / liquid_shares
-        } else {
-            keeper_fee
+        let config: Option<KeeperFeeConfig> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::VaultKeeperFee(vault_id));
+        let fee = match config {
+            Some(config) => from_config(config),
+            None if vault.keeper_fee > 0 => vault.keeper_fee,
+            None => match env.storage().instance().get(&DataKey::DefaultKeeperFee) {
+                Some(config) => from_config(config),
+                None => 0,
+            },
         };
+        Ok(if !sponsored && fee >= shares { 0 } else { fee })
+    }
+
+    fn require_valid_keeper_fee(config: &KeeperFeeConfig) -> Result<(), Error> {
+        if config.bps > MAX_KEEPER_FEE_BPS || config.min_fee < 0 || config.max_fee < config.min_fee
+        {
+            return Err(Error::InvalidKeeperFee);
+        }
+        Ok(())
+    }
+
+    // Admin-only: set (or with None, clear) the keeper fee applied to vaults
+    // without a fee of their own
+    pub fn set_default_keeper_fee(env: Env, config: Option<KeeperFeeConfig>) -> Result<(), Error> {
+        Self::require_admin(&env)?;
+
+        match &config {
+            Some(config) => {
+                Self::require_valid_keeper_fee(config)?;
+                env.storage()
+                    .instance()
+                    .set(&DataKey::DefaultKeeperFee, config);
+            }
+            None => env.storage().instance().remove(&DataKey::DefaultKeeperFee),
+        }
+
+        Self::record_admin_action(
+            &env,
+            AdminAction::KeeperFeeSet,
+            None,
+            vec![&env, config.into_val(&env)],
+        )?;
+
+        KeeperFeeConfigured {
+            vault_id: None,
+            config,
+            version: EVENT_VERSION,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    // Admin-only: override (or with None, stop overriding) one vault's keeper fee
+    pub fn set_vault_keeper_fee(
+        env: Env,
+        vault_id: u64,
+        config: Option<KeeperFeeConfig>,
+    ) -> Result<(), Error> {
+        Self::require_admin(&env)?;
+        Self::load_vault(&env, vault_id)?;
+
+        let key = DataKey::VaultKeeperFee(vault_id);
+        match &config {
+            Some(config) => {
+                Self::require_valid_keeper_fee(config)?;
+                Self::set_persistent(&env, &key, config);
+            }
+            None => env.storage().persistent().remove(&key),
+        }
+
+        Self::record_admin_action(
+            &env,
+            AdminAction::KeeperFeeSet,
+            Some(vault_id),
+            vec![&env, config.into_val(&env)],
+        )?;
+
+        KeeperFeeConfigured {
+            vault_id: Some(vault_id),
+            config,
+            version: EVENT_VERSION,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    pub fn get_default_keeper_fee(env: Env) -> Option<KeeperFeeConfig> {
+        env.storage().instance().get(&DataKey::DefaultKeeperFee)
+    }
+
+    pub fn get_vault_keeper_fee(env: Env, vault_id: u64) -> Option<KeeperFeeConfig> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::VaultKeeperFee(vault_id))
+    }
+
+    // Fee in shares a keeper would earn by auto-claiming the vault right now
+    pub fn quote_keeper_fee(env: Env, vault_id: u64) -> Result<i128, Error> {
+        let vault: Vault = Self::load_vault(&env, vault_id)?;
+        let claimable = Self::unlocked_for(&env, vault_id, &vault)? - vault.released_amount;
+        if claimable <= 0 {
+            return Ok(0);
+        }
+        let sponsored = env
+            .storage()
+            .persistent()
+            .has(&DataKey::KeeperSponsored(vault_id));
+        Self::keeper_fee_for(&env, vault_id, &vault, claimable, sponsored)
+    }
+
+    // Admin-only: move `amount` from the admin balance into the keeper reward
+    // pool. Returns the new pool balance.
+    pub fn fund_keeper_pool(env: Env, amount: i128) -> Result<i128, Error> {
+        Self::require_admin(&env)?;
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
 
-        vault.released_amount += claimable;
-        let mut updated_total_shares = total_shares;
-        updated_total_shares -= claimable;
+        let admin_balance: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::AdminBalance)
+            .unwrap_or(0);
+        if amount > admin_balance {
+            return Err(Error::InsufficientAdminBalance);
+        }
+        let pool: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::KeeperPool)
+            .unwrap_or(0);
+        let pool_balance = pool + amount;
         env.storage()
             .instance()
-            .set(&DataKey::TotalShares, &updated_total_shares);
+            .set(&DataKey::AdminBalance, &(admin_balance - amount));
         env.storage()
             .instance()
-            .set(&DataKey::VaultData(vault_id), &vault);
+            .set(&DataKey::KeeperPool, &pool_balance);
 
-        token_client.transfer(
-            &env.current_contract_address(),
-            &vault.owner,
-            &beneficiary_tokens,
-        );
-        token_client.transfer(&env.current_contract_address(), &keeper, &keeper_tokens);
+        Self::record_admin_action(
+            &env,
+            AdminAction::KeeperPoolFunded,
+            None,
+            vec![&env, amount.into_val(&env)],
+        )?;
+
+        KeeperPoolFunded {
+            amount,
+            pool_balance,
+            version: EVENT_VERSION,
+        }
+        .publish(&env);
+        Ok(pool_balance)
+    }
+
+    // Admin-only: return `amount` of the unspent pool to the admin balance.
+    // Fees already credited to keepers stay withdrawable. Returns the new pool balance.
+    pub fn withdraw_keeper_pool(env: Env, amount: i128) -> Result<i128, Error> {
+        Self::require_admin(&env)?;
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
 
-        let mut fees: Map<Address, i128> = env
+        let pool: i128 = env
             .storage()
             .instance()
-            .get(&DataKey::KeeperFees)
-            .unwrap_or(Map::new(&env));
-        let current_fees = fees.get(keeper.clone()).unwrap_or(0);
-        fees.set(keeper.clone(), current_fees + keeper_fee);
+            .get(&DataKey::KeeperPool)
+            .unwrap_or(0);
+        if amount > pool {
+            return Err(Error::KeeperPoolInsufficient);
+        }
+        let pool_balance = pool - amount;
         env.storage()
             .instance()
-            .set(&DataKey::KeeperFees, &fees);
+            .set(&DataKey::KeeperPool, &pool_balance);
+        Self::add_to_total(&env, DataKey::AdminBalance, amount);
 
-        env.events().publish(
-            (Symbol::new(&env, "KeeperClaim"), vault_id),
-            (keeper, beneficiary_amount, keeper_fee),
-        );
+        Self::record_admin_action(
+            &env,
+            AdminAction::KeeperPoolWithdrawn,
+            None,
+            vec![&env, amount.into_val(&env)],
+        )?;
+
+        KeeperPoolWithdrawn {
+            amount,
+            pool_balance,
+            version: EVENT_VERSION,
+        }
+        .publish(&env);
+        Ok(pool_balance)
     }
 
-    // Get accumulated fees for a keeper
-    pub fn get_keeper_fee(env: Env, keeper: Address) -> i128 {
-        let fees: Map<Address, i128> = env
+    // Admin-only: opt a vault into (or out of) having its keeper fees paid
+    // from the reward pool instead of the beneficiary's claim
+    pub fn set_keeper_sponsorship(env: Env, vault_id: u64, sponsored: bool) -> Result<(), Error> {
+        Self::require_admin(&env)?;
+        Self::load_vault(&env, vault_id)?;
+
+        let key = DataKey::KeeperSponsored(vault_id);
+        if sponsored {
+            Self::set_persistent(&env, &key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+
+        Self::record_admin_action(
+            &env,
+            AdminAction::KeeperSponsorshipSet,
+            Some(vault_id),
+            vec![&env, sponsored.into_val(&env)],
+        )?;
+
+        KeeperSponsorshipSet {
+            vault_id,
+            sponsored,
+            version: EVENT_VERSION,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    pub fn is_keeper_sponsored(env: Env, vault_id: u64) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::KeeperSponsored(vault_id))
+    }
+
+    pub fn get_keeper_pool(env: Env) -> KeeperPool {
+        let storage = env.storage().instance();
+        KeeperPool {
+            balance: storage.get(&DataKey::KeeperPool).unwrap_or(0),
+            owed: storage.get(&DataKey::KeeperOwed).unwrap_or(0),
+        }
+    }
+
+    // Withdraw every sponsored fee credited to `keeper`. Returns the tokens paid.
+    pub fn withdraw_keeper_fees(env: Env, keeper: Address) -> Result<i128, Error> {
+        keeper.require_auth();
+        Self::require_not_deprecated(&env)?;
+        Self::require_scope_active(&env, PauseScope::Keeper)?;
+
+        let mut ledger = Self::load_keeper_ledger(&env, &keeper);
+        let amount = ledger.sponsored - ledger.withdrawn;
+        if amount <= 0 {
+            return Err(Error::NothingToWithdraw);
+        }
+
+        ledger.withdrawn += amount;
+        Self::save_keeper_ledger(&env, &keeper, &ledger);
+        Self::add_to_total(&env, DataKey::KeeperOwed, -amount);
+        Self::get_token_client(&env)?.transfer(&env.current_contract_address(), &keeper, &amount);
+
+        KeeperFeesWithdrawn {
+            keeper,
+            amount,
+            version: EVENT_VERSION,
+        }
+        .publish(&env);
+        Ok(amount)
+    }
+
+    pub fn get_keeper_ledger(env: Env, keeper: Address) -> KeeperLedger {
+        Self::load_keeper_ledger(&env, &keeper)
+    }
+
+    fn load_keeper_ledger(env: &Env, keeper: &Address) -> KeeperLedger {
+        if let Some(ledger) = env
+            .storage()
+            .persistent()
+            .get(&DataKey::KeeperLedger(keeper.clone()))
+        {
+            return ledger;
+        }
+        // Keepers paid before the ledger existed only have a running fee
+        // total, in the schema 1 instance map or a per-keeper entry
+        let deducted = env
             .storage()
+            .persistent()
+            .get(&DataKey::KeeperFees(keeper.clone()))
+            .or_else(|| Self::legacy_keeper_fees(env).and_then(|fees| fees.get(keeper.clone())))
+            .unwrap_or(0);
+        KeeperLedger {
+            deducted,
+            sponsored: 0,
+            withdrawn: 0,
+            claims: 0,
+        }
+    }
+
+    fn save_keeper_ledger(env: &Env, keeper: &Address, ledger: &KeeperLedger) {
+        Self::set_persistent(env, &DataKey::KeeperLedger(keeper.clone()), ledger);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::KeeperFees(keeper.clone()));
+        if let Some(mut fees) = Self::legacy_keeper_fees(env) {
+            if fees.contains_key(keeper.clone()) {
+                fees.remove(keeper.clone());
+                let key = vec![env, Symbol::new(env, "KeeperFees")];
+                if fees.is_empty() {
+                    env.storage().instance().remove(&key);
+                } else {
+                    env.storage().instance().set(&key, &fees);
+                }
+            }
+        }
+    }
+
+    // Schema 1 kept every keeper's fee total in one instance-storage map
+    // under the unit key `KeeperFees`
+    fn legacy_keeper_fees(env: &Env) -> Option<Map<Address, i128>> {
+        env.storage()
             .instance()
-            .get(&DataKey::KeeperFees)
-            .unwrap_or(Map::new(&env));
-        fees.get(keeper).unwrap_or(0)
+            .get(&vec![env, Symbol::new(env, "KeeperFees")])
+    }
+
+    // Total tokens a keeper has earned in fees, deducted and sponsored alike
+    pub fn get_keeper_fee(env: Env, keeper: Address) -> i128 {
+        let ledger = Self::load_keeper_ledger(&env, &keeper);
+        ledger.deducted + ledger.sponsored
     }
 
     // Rescue tokens accidentally sent directly to the contract address.
     // Calculates unallocated_balance = contract_token_balance - total_vault_liabilities
     // and transfers it to the admin.
-    pub fn rescue_unallocated_tokens(env: Env, token_address: Address) -> i128 {
-        Self::require_admin(&env);
+    pub fn rescue_unallocated_tokens(env: Env, token_address: Address) -> Result<i128, Error> {
+        Self::require_admin(&env)?;
 
         if !Self::is_token_whitelisted(&env, &token_address) {
-            panic!("Token is not whitelisted");
+            return Err(Error::TokenNotWhitelisted);
+        }
+        // Liabilities are only known once every vault is in the totals
+        if env.storage().instance().has(&DataKey::TotalsRecount) {
+            return Err(Error::MigrationPending);
         }
 
         let token_client = token::Client::new(&env, &token_address);
@@ -2406,41 +5032,27 @@ fn calculate_time_vested_amount(env: &Env, vault: &Vault) -> i128 {
 
         if let Some(main_token) = env.storage().instance().get::<_, Address>(&DataKey::Token) {
             if main_token == token_address {
-                panic!("Cannot rescue yield-bearing token. Yield is distributed to beneficiaries on claim.");
+                return Err(Error::CannotRescueYieldToken);
             }
         }
 
-        let vault_count: u64 = env
+        let total_liabilities: i128 = env
             .storage()
             .instance()
-            .get(&DataKey::VaultCount)
+            .get(&DataKey::TotalShares)
             .unwrap_or(0);
 
-        let mut total_liabilities: i128 = 0;
-        for i in 1..=vault_count {
-            if let Some(vault) = env
-                .storage()
-                .instance()
-                .get::<DataKey, Vault>(&DataKey::VaultData(i))
-            {
-                let unreleased = vault.total_amount - vault.released_amount;
-                if unreleased > 0 {
-                    total_liabilities += unreleased;
-                }
-            }
-        }
-
         let unallocated_balance = contract_balance - total_liabilities;
 
         if unallocated_balance <= 0 {
-            panic!("No unallocated tokens to rescue");
+            return Err(Error::NoUnallocatedTokens);
         }
 
         let admin: Address = env
             .storage()
             .instance()
             .get(&DataKey::AdminAddress)
-            .unwrap_or_else(|| panic!("Admin not set"));
+            .ok_or(Error::AdminNotSet)?;
 
         token_client.transfer(
             &env.current_contract_address(),
@@ -2448,16 +5060,53 @@ fn calculate_time_vested_amount(env: &Env, vault: &Vault) -> i128 {
             &unallocated_balance,
         );
 
-        env.events().publish(
-            (Symbol::new(&env, "RescueExecuted"), token_address),
-            (unallocated_balance, admin),
-        );
+        Self::record_admin_action(
+            &env,
+            AdminAction::TokensRescued,
+            None,
+            vec![
+                &env,
+                token_address.into_val(&env),
+                unallocated_balance.into_val(&env),
+            ],
+        )?;
+
+        RescueExecuted {
+            token: token_address,
+            amount: unallocated_balance,
+            recipient: admin,
+            version: EVENT_VERSION,
+        }
+        .publish(&env);
+
+        Ok(unallocated_balance)
+    }
+}
+
+/// Helper functions for common time durations in seconds
+/// These can be used when creating vaults with periodic vesting
+impl VestingContract {
+    /// Convert days to seconds
+    pub const fn seconds(days: u64) -> u64 {
+        days * 86400
+    }
+
+    /// 30 days in seconds (monthly vesting)
+    pub const fn monthly() -> u64 {
+        30 * 86400 // 2,592,000 seconds
+    }
+
+    /// 90 days in seconds (quarterly vesting)
+    pub const fn quarterly() -> u64 {
+        3 * 30 * 86400 // 7,776,000 seconds
+    }
 
-        unallocated_balance
+    /// 365 days in seconds (yearly vesting)
+    pub const fn yearly() -> u64 {
+        365 * 86400 // 31,536,000 seconds
     }
 }
 
 // Unit tests for this contract are kept as integration tests under
 // `contracts/vesting_contracts/tests/` to avoid `no_std` test-harness friction.
-// mod test;
 // mod test; // Disabled - tests need refactoring
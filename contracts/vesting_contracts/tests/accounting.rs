@@ -0,0 +1,41 @@
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address,
+};
+
+use vesting_contracts::AccountingTotals;
+
+mod common;
+use common::{create, setup, Setup};
+
+#[test]
+fn running_totals_track_claims_and_revocations() {
+    let Setup { env, client, .. } = setup();
+    let owner = Address::generate(&env);
+    let claimed = create(&client, &owner, 1_000, 0);
+    let revoked = create(&client, &owner, 1_000, 0);
+    let partial = create(&client, &owner, 1_000, 0);
+    let clawed = create(&client, &owner, 1_000, 0);
+
+    client.clawback_vault(&clawed);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 500);
+    client.claim_tokens(&claimed, &300i128);
+    client.revoke_tokens(&revoked);
+    client.revoke_partial(&partial, &200i128);
+
+    assert_eq!(
+        client.get_accounting_totals(),
+        AccountingTotals {
+            locked: 700 + 800,
+            released: 300,
+            revoked: 1_000 + 200 + 1_000,
+            staked: 0,
+        }
+    );
+
+    let (locked, claimed_total, admin_balance) = client.get_contract_state();
+    assert_eq!(locked, 1_500);
+    assert_eq!(claimed_total, 2_500);
+    assert_eq!(admin_balance, 1_000_000 - 4_000 + 2_200);
+    assert!(client.check_invariant());
+}
@@ -0,0 +1,43 @@
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address,
+};
+
+use vesting_contracts::Error;
+
+mod common;
+use common::{create, setup, Setup};
+
+#[test]
+fn completed_vault_is_compacted_and_unindexed() {
+    let Setup { env, client, .. } = setup();
+    let owner = Address::generate(&env);
+    let done = create(&client, &owner, 1_000, 0);
+    let active = create(&client, &owner, 1_000, 0);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1_000);
+    client.claim_tokens(&done, &1_000i128);
+
+    env.set_auths(&[]);
+    let archived = client.archive_vault(&done);
+    assert_eq!(archived.owner, owner);
+    assert_eq!(archived.total_amount, 1_000);
+    assert_eq!(archived.archived_at, env.ledger().timestamp());
+
+    assert_eq!(client.get_archived_vault(&done), Some(archived));
+    assert_eq!(client.get_archived_vault(&active), None);
+    assert_eq!(client.get_user_vaults(&owner), vec![&env, active]);
+    assert!(client.try_get_vault(&done).is_err());
+}
+
+#[test]
+fn vault_with_unreleased_tokens_cannot_be_archived() {
+    let Setup { env, client, .. } = setup();
+    let owner = Address::generate(&env);
+    let vault_id = create(&client, &owner, 1_000, 0);
+
+    assert_eq!(
+        client.try_archive_vault(&vault_id),
+        Err(Ok(Error::VaultNotFullyReleased))
+    );
+}
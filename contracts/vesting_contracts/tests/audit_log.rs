@@ -0,0 +1,105 @@
+use soroban_sdk::{
+    testutils::{storage::Persistent as _, Address as _, Ledger},
+    vec, Address, String, TryFromVal,
+};
+
+use vesting_contracts::{AdminAction, DataKey, Milestone, PERSISTENT_TTL_EXTEND_TO};
+
+mod common;
+use common::{create, setup, Setup};
+
+#[test]
+fn admin_actions_are_logged_per_vault_and_globally() {
+    let Setup {
+        env, client, admin, ..
+    } = setup();
+    let beneficiary = Address::generate(&env);
+    let vault_id = create(&client, &beneficiary, 1_000, 0);
+    let other_id = create(&client, &beneficiary, 1_000, 0);
+
+    client.set_vault_title(&vault_id, &String::from_str(&env, "Series A"));
+    client.freeze_vault(&vault_id);
+    client.unfreeze_vault(&vault_id);
+    client.set_milestones(
+        &vault_id,
+        &vec![
+            &env,
+            Milestone {
+                id: 1,
+                percentage: 50,
+                is_unlocked: false,
+            },
+        ],
+    );
+    client.unlock_milestone(&vault_id, &1u64);
+    client.revoke_tokens(&other_id);
+
+    assert_eq!(client.get_audit_log_count(), 8);
+
+    let vault_log = client.get_vault_audit_log(&vault_id, &0u32, &10u32);
+    let actions: std::vec::Vec<AdminAction> = vault_log.iter().map(|e| e.action).collect();
+    assert_eq!(
+        actions,
+        [
+            AdminAction::VaultCreated,
+            AdminAction::TitleSet,
+            AdminAction::VaultFrozen,
+            AdminAction::VaultUnfrozen,
+            AdminAction::MilestonesSet,
+            AdminAction::MilestoneUnlocked,
+        ]
+    );
+    for entry in vault_log.iter() {
+        assert_eq!(entry.actor, admin);
+        assert_eq!(entry.vault_id, Some(vault_id));
+    }
+
+    let revoke = client.get_audit_log(&8u64, &10u32).get(0).unwrap();
+    assert_eq!(revoke.action, AdminAction::TokensRevoked);
+    assert_eq!(revoke.vault_id, Some(other_id));
+    let returned = i128::try_from_val(&env, &revoke.params.get(0).unwrap()).unwrap();
+    assert_eq!(returned, 1_000);
+}
+
+#[test]
+fn audit_log_pages_are_bounded() {
+    let Setup { env, client, .. } = setup();
+    let beneficiary = Address::generate(&env);
+    for _ in 0..5 {
+        create(&client, &beneficiary, 1_000, 0);
+    }
+
+    let first_page = client.get_audit_log(&1u64, &2u32);
+    assert_eq!(first_page.len(), 2);
+    assert_eq!(first_page.get(0).unwrap().id, 1);
+
+    let last_page = client.get_audit_log(&5u64, &2u32);
+    assert_eq!(last_page.len(), 1);
+    assert_eq!(last_page.get(0).unwrap().id, 5);
+
+    assert!(client.get_audit_log(&6u64, &2u32).is_empty());
+}
+
+#[test]
+fn anyone_can_extend_audit_entries() {
+    let Setup { env, client, .. } = setup();
+    let beneficiary = Address::generate(&env);
+    for _ in 0..3 {
+        create(&client, &beneficiary, 1_000, 0);
+    }
+
+    env.ledger()
+        .set_sequence_number(env.ledger().sequence() + 1_000);
+    env.set_auths(&[]);
+    assert_eq!(client.extend_audit_log_ttl(&1u64, &2u32), Some(3));
+    assert_eq!(client.extend_audit_log_ttl(&3u64, &2u32), None);
+
+    env.as_contract(&client.address, || {
+        for id in 1..=3u64 {
+            assert_eq!(
+                env.storage().persistent().get_ttl(&DataKey::AuditEntry(id)),
+                PERSISTENT_TTL_EXTEND_TO
+            );
+        }
+    });
+}
@@ -0,0 +1,181 @@
+use soroban_sdk::{
+    testutils::{Address as _, MockAuth, MockAuthInvoke},
+    vec, Address, IntoVal, Vec,
+};
+
+use vesting_contracts::{AutoClaimPolicy, AutoClaimStatus, Error};
+
+mod common;
+use common::{advance, create, setup, Setup};
+
+#[test]
+fn policy_limits_keepers_interval_and_claim_size() {
+    let Setup { env, client, .. } = setup();
+    let alice = Address::generate(&env);
+    let keeper = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    let vault_id = create(&client, &alice, 1_000, 0);
+
+    let policy = AutoClaimPolicy {
+        enabled: true,
+        min_interval: 300,
+        min_claim: 100,
+        allowed_keepers: vec![&env, keeper.clone()],
+    };
+    client.set_auto_claim_policy(&vault_id, &Some(policy.clone()));
+    assert_eq!(client.get_auto_claim_policy(&vault_id), Some(policy));
+
+    advance(&env, 50);
+    assert_eq!(
+        client.try_auto_claim(&vault_id, &keeper),
+        Err(Ok(Error::AutoClaimBelowMinimum))
+    );
+    advance(&env, 150);
+    assert_eq!(
+        client.try_auto_claim(&vault_id, &stranger),
+        Err(Ok(Error::KeeperNotPermitted))
+    );
+    assert_eq!(client.auto_claim(&vault_id, &keeper), 200);
+    assert_eq!(
+        client.get_last_auto_claim(&vault_id),
+        Some(env.ledger().timestamp())
+    );
+
+    advance(&env, 200);
+    assert_eq!(
+        client.try_auto_claim(&vault_id, &keeper),
+        Err(Ok(Error::AutoClaimTooSoon))
+    );
+    // The owner can still claim whenever they like
+    assert_eq!(client.claim_tokens(&vault_id, &50i128), 50);
+
+    advance(&env, 100);
+    assert_eq!(client.auto_claim(&vault_id, &keeper), 250);
+}
+
+#[test]
+fn disabled_policy_blocks_keepers_and_batches_skip_it() {
+    let Setup { env, client, .. } = setup();
+    let alice = Address::generate(&env);
+    let keeper = Address::generate(&env);
+    let blocked = create(&client, &alice, 1_000, 0);
+    let open = create(&client, &alice, 1_000, 0);
+    client.set_auto_claim_policy(
+        &blocked,
+        &Some(AutoClaimPolicy {
+            enabled: false,
+            min_interval: 0,
+            min_claim: 0,
+            allowed_keepers: Vec::new(&env),
+        }),
+    );
+    advance(&env, 1_000);
+
+    assert_eq!(
+        client.try_auto_claim(&blocked, &keeper),
+        Err(Ok(Error::AutoClaimDisabled))
+    );
+    let results = client.batch_auto_claim(&vec![&env, blocked, open], &keeper);
+    assert_eq!(
+        results.get(0).unwrap().status,
+        AutoClaimStatus::PolicyBlocked
+    );
+    assert_eq!(results.get(1).unwrap().status, AutoClaimStatus::Claimed);
+
+    client.set_auto_claim_policy(&blocked, &None);
+    assert_eq!(client.auto_claim(&blocked, &keeper), 1_000);
+}
+
+#[test]
+fn callers_cannot_claim_in_an_allowed_keepers_name() {
+    let Setup { env, client, .. } = setup();
+    let alice = Address::generate(&env);
+    let keeper = Address::generate(&env);
+    let impostor = Address::generate(&env);
+    let vault_id = create(&client, &alice, 1_000, 0);
+    client.set_auto_claim_policy(
+        &vault_id,
+        &Some(AutoClaimPolicy {
+            enabled: true,
+            min_interval: 0,
+            min_claim: 0,
+            allowed_keepers: vec![&env, keeper.clone()],
+        }),
+    );
+    advance(&env, 500);
+
+    // Only the impostor signs, naming the allowed keeper
+    env.mock_auths(&[MockAuth {
+        address: &impostor,
+        invoke: &MockAuthInvoke {
+            contract: &client.address,
+            fn_name: "auto_claim",
+            args: (vault_id, keeper.clone()).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    assert!(client.try_auto_claim(&vault_id, &keeper).is_err());
+
+    let ids = vec![&env, vault_id];
+    env.mock_auths(&[MockAuth {
+        address: &impostor,
+        invoke: &MockAuthInvoke {
+            contract: &client.address,
+            fn_name: "batch_auto_claim",
+            args: (ids.clone(), keeper.clone()).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    assert!(client.try_batch_auto_claim(&ids, &keeper).is_err());
+    assert_eq!(client.get_vault(&vault_id).released_amount, 0);
+    assert_eq!(client.get_last_auto_claim(&vault_id), None);
+
+    env.mock_auths(&[MockAuth {
+        address: &keeper,
+        invoke: &MockAuthInvoke {
+            contract: &client.address,
+            fn_name: "auto_claim",
+            args: (vault_id, keeper.clone()).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    assert_eq!(client.auto_claim(&vault_id, &keeper), 500);
+}
+
+#[test]
+fn a_new_beneficiary_does_not_inherit_the_policy() {
+    let Setup { env, client, .. } = setup();
+    let alice = Address::generate(&env);
+    let keeper = Address::generate(&env);
+    let now = env.ledger().timestamp();
+    let vault_id = client.create_vault_full(
+        &alice,
+        &1_000i128,
+        &now,
+        &(now + 1_000),
+        &0i128,
+        &true,
+        &true,
+        &0u64,
+    );
+    let policy = AutoClaimPolicy {
+        enabled: false,
+        min_interval: 0,
+        min_claim: 0,
+        allowed_keepers: Vec::new(&env),
+    };
+
+    let handovers: [&dyn Fn(); 3] = [
+        &|| client.transfer_vault(&vault_id, &Address::generate(&env)),
+        &|| client.rotate_beneficiary_key(&vault_id, &Address::generate(&env)),
+        &|| client.transfer_beneficiary(&vault_id, &Address::generate(&env)),
+    ];
+    for handover in handovers {
+        client.set_auto_claim_policy(&vault_id, &Some(policy.clone()));
+        handover();
+        assert_eq!(client.get_auto_claim_policy(&vault_id), None);
+    }
+
+    advance(&env, 100);
+    assert_eq!(client.auto_claim(&vault_id, &keeper), 100);
+}
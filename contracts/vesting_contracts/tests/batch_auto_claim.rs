@@ -0,0 +1,130 @@
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger},
+    token, vec, Address,
+};
+
+use vesting_contracts::{AutoClaimResult, AutoClaimStatus, Error, PauseScope};
+
+mod common;
+use common::{create_at, setup, Setup};
+
+fn skipped(vault_id: u64, status: AutoClaimStatus) -> AutoClaimResult {
+    AutoClaimResult {
+        vault_id,
+        status,
+        shares: 0,
+        amount: 0,
+        keeper_fee: 0,
+    }
+}
+
+#[test]
+fn batch_auto_claim_skips_and_pays_keeper_once() {
+    let Setup {
+        env,
+        client,
+        token: token_addr,
+        ..
+    } = setup();
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let keeper = Address::generate(&env);
+    let now = env.ledger().timestamp();
+
+    let v1 = create_at(&client, &alice, 1_000, 10, now);
+    let v2 = create_at(&client, &bob, 2_000, 20, now);
+    let frozen = create_at(&client, &alice, 1_000, 10, now);
+    let small = create_at(&client, &bob, 100, 80, now);
+    let not_started = create_at(&client, &alice, 1_000, 10, now + 10_000);
+    client.freeze_vault(&frozen);
+
+    env.ledger().set_timestamp(now + 500);
+    let results = client.batch_auto_claim(&vec![&env, v1, frozen, small, v2, not_started], &keeper);
+
+    assert_eq!(
+        results,
+        vec![
+            &env,
+            AutoClaimResult {
+                vault_id: v1,
+                status: AutoClaimStatus::Claimed,
+                shares: 490,
+                amount: 490,
+                keeper_fee: 10,
+            },
+            skipped(frozen, AutoClaimStatus::Frozen),
+            // The fixed fee would take the whole claim, so it is waived
+            AutoClaimResult {
+                vault_id: small,
+                status: AutoClaimStatus::Claimed,
+                shares: 50,
+                amount: 50,
+                keeper_fee: 0,
+            },
+            AutoClaimResult {
+                vault_id: v2,
+                status: AutoClaimStatus::Claimed,
+                shares: 980,
+                amount: 980,
+                keeper_fee: 20,
+            },
+            skipped(not_started, AutoClaimStatus::NothingToClaim),
+        ]
+    );
+
+    // Three beneficiary transfers plus one aggregated keeper transfer
+    let transfers = env
+        .events()
+        .all()
+        .filter_by_contract(&token_addr)
+        .events()
+        .len();
+    assert_eq!(transfers, 4);
+
+    let token = token::Client::new(&env, &token_addr);
+    assert_eq!(token.balance(&alice), 490);
+    assert_eq!(token.balance(&bob), 1_030);
+    assert_eq!(token.balance(&keeper), 30);
+    assert_eq!(client.get_keeper_fee(&keeper), 30);
+    assert_eq!(client.get_vault(&frozen).released_amount, 0);
+    assert_eq!(client.get_vault(&small).released_amount, 50);
+}
+
+#[test]
+fn batch_auto_claim_respects_keeper_pause_and_skips_missing_vaults() {
+    let Setup { env, client, .. } = setup();
+    let alice = Address::generate(&env);
+    let keeper = Address::generate(&env);
+    let now = env.ledger().timestamp();
+    let vault_id = create_at(&client, &alice, 1_000, 10, now);
+    let lazy = client.create_vault_lazy(
+        &alice,
+        &1_000i128,
+        &now,
+        &(now + 1_000),
+        &10i128,
+        &true,
+        &false,
+        &0u64,
+    );
+    env.ledger().set_timestamp(now + 1_000);
+
+    client.set_scope_paused(&PauseScope::Keeper, &true);
+    assert_eq!(
+        client.try_batch_auto_claim(&vec![&env, vault_id], &keeper),
+        Err(Ok(Error::KeeperPaused))
+    );
+    client.set_scope_paused(&PauseScope::Keeper, &false);
+
+    let results = client.batch_auto_claim(&vec![&env, 999, lazy, vault_id], &keeper);
+    assert_eq!(
+        results.get(0).unwrap(),
+        skipped(999, AutoClaimStatus::NotFound)
+    );
+    assert_eq!(
+        results.get(1).unwrap(),
+        skipped(lazy, AutoClaimStatus::NotInitialized)
+    );
+    assert_eq!(results.get(2).unwrap().status, AutoClaimStatus::Claimed);
+    assert_eq!(client.get_vault(&vault_id).released_amount, 1_000);
+}
@@ -0,0 +1,98 @@
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger},
+    token, vec, Address,
+};
+
+use vesting_contracts::{Error, PauseScope, VaultClaim};
+
+mod common;
+use common::{create_at, setup, Setup};
+
+#[test]
+fn claim_all_pays_every_vault_in_one_transfer() {
+    let Setup {
+        env,
+        client,
+        token: token_addr,
+        ..
+    } = setup();
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let now = env.ledger().timestamp();
+
+    let v1 = create_at(&client, &alice, 1_000, 0, now);
+    let v2 = create_at(&client, &alice, 2_000, 0, now);
+    let frozen = create_at(&client, &alice, 500, 0, now);
+    let not_started = create_at(&client, &alice, 500, 0, now + 10_000);
+    let other = create_at(&client, &bob, 1_000, 0, now);
+    client.freeze_vault(&frozen);
+
+    env.ledger().set_timestamp(now + 500);
+    client.claim_tokens(&v1, &100i128);
+    let claims = client.claim_all(&alice, &None);
+
+    assert_eq!(
+        claims,
+        vec![
+            &env,
+            VaultClaim {
+                vault_id: v1,
+                token: token_addr.clone(),
+                shares: 400,
+                amount: 400,
+            },
+            VaultClaim {
+                vault_id: v2,
+                token: token_addr.clone(),
+                shares: 1_000,
+                amount: 1_000,
+            },
+        ]
+    );
+    let transfers = env
+        .events()
+        .all()
+        .filter_by_contract(&token_addr)
+        .events()
+        .len();
+    assert_eq!(transfers, 1);
+
+    let token = token::Client::new(&env, &token_addr);
+    assert_eq!(token.balance(&alice), 1_500);
+    assert_eq!(client.get_vault(&v1).released_amount, 500);
+    assert_eq!(client.get_vault(&frozen).released_amount, 0);
+    assert_eq!(client.get_vault(&not_started).released_amount, 0);
+    assert_eq!(client.get_vault(&other).released_amount, 0);
+
+    // Everything is claimed now, so a second call has nothing to do
+    assert_eq!(client.claim_all(&alice, &None).len(), 0);
+}
+
+#[test]
+fn claim_all_filters_by_token_and_respects_pause() {
+    let Setup {
+        env,
+        client,
+        token: token_addr,
+        ..
+    } = setup();
+    let alice = Address::generate(&env);
+    let now = env.ledger().timestamp();
+    let vault_id = create_at(&client, &alice, 1_000, 0, now);
+    env.ledger().set_timestamp(now + 1_000);
+
+    let other_token = Address::generate(&env);
+    assert_eq!(client.claim_all(&alice, &Some(other_token)).len(), 0);
+    assert_eq!(client.get_vault(&vault_id).released_amount, 0);
+
+    client.set_scope_paused(&PauseScope::Claims, &true);
+    assert_eq!(
+        client.try_claim_all(&alice, &Some(token_addr.clone())),
+        Err(Ok(Error::ClaimsPaused))
+    );
+    client.set_scope_paused(&PauseScope::Claims, &false);
+
+    let claims = client.claim_all(&alice, &Some(token_addr));
+    assert_eq!(claims.len(), 1);
+    assert_eq!(claims.get(0).unwrap().shares, 1_000);
+}
@@ -0,0 +1,198 @@
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as _, Ledger},
+    token, vec, Address, Env, Symbol,
+};
+
+use vesting_contracts::{AccountingTotals, Error, Milestone, VestingContractClient};
+
+mod common;
+use common::{create, Setup};
+
+#[contract]
+pub struct MockStaking;
+
+#[contractimpl]
+impl MockStaking {
+    pub fn stake(_env: Env, _vault_id: u64, _amount: i128, _validator: Address) {}
+    pub fn unstake(_env: Env, _vault_id: u64, _amount: i128) {}
+}
+
+#[contract]
+pub struct MockTarget;
+
+#[contractimpl]
+impl MockTarget {
+    pub fn on_claim(_env: Env) {}
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Path {
+    Owner,
+    Delegate,
+    Call,
+    Keeper,
+}
+
+const PATHS: [Path; 4] = [Path::Owner, Path::Delegate, Path::Call, Path::Keeper];
+
+struct Fixture {
+    env: Env,
+    client: VestingContractClient<'static>,
+    token: token::Client<'static>,
+    owner: Address,
+    target: Address,
+    vault_id: u64,
+}
+
+// One 1_000-token vault vesting linearly over 1_000 seconds, with a delegate
+// and a staking contract configured
+fn fixture() -> Fixture {
+    let Setup {
+        env,
+        client,
+        token: token_addr,
+        ..
+    } = common::setup();
+    client.set_staking_contract(&env.register(MockStaking, ()));
+
+    let owner = Address::generate(&env);
+    let vault_id = create(&client, &owner, 1_000, 0);
+    client.set_delegate(&vault_id, &Some(Address::generate(&env)));
+
+    let target = env.register(MockTarget, ());
+    let token = token::Client::new(&env, &token_addr);
+    Fixture {
+        env,
+        client,
+        token,
+        owner,
+        target,
+        vault_id,
+    }
+}
+
+impl Fixture {
+    // Keeper claims always take everything unlocked, so `amount` only applies
+    // to the other paths
+    fn claim(&self, path: Path, amount: i128) -> Result<i128, Error> {
+        let result = match path {
+            Path::Owner => self.client.try_claim_tokens(&self.vault_id, &amount),
+            Path::Delegate => self.client.try_claim_as_delegate(&self.vault_id, &amount),
+            Path::Call => self.client.try_claim_and_call(
+                &self.vault_id,
+                &amount,
+                &self.target,
+                &Symbol::new(&self.env, "on_claim"),
+                &vec![&self.env],
+            ),
+            Path::Keeper => self
+                .client
+                .try_auto_claim(&self.vault_id, &Address::generate(&self.env)),
+        };
+        match result {
+            Ok(Ok(shares)) => Ok(shares),
+            Err(Ok(error)) => Err(error),
+            other => panic!("unexpected claim result {other:?}"),
+        }
+    }
+
+    fn payout_balance(&self, path: Path) -> i128 {
+        match path {
+            Path::Call => self.token.balance(&self.target),
+            _ => self.token.balance(&self.owner),
+        }
+    }
+
+    fn advance(&self, seconds: u64) {
+        self.env
+            .ledger()
+            .set_timestamp(self.env.ledger().timestamp() + seconds);
+    }
+}
+
+#[test]
+fn every_path_releases_and_pays_the_same() {
+    let mut outcomes: std::vec::Vec<(i128, i128, i128, AccountingTotals)> = std::vec::Vec::new();
+    for path in PATHS {
+        let f = fixture();
+        f.advance(500);
+        let shares = f.claim(path, 500).unwrap();
+        let vault = f.client.get_vault(&f.vault_id);
+        outcomes.push((
+            shares,
+            vault.released_amount,
+            f.payout_balance(path),
+            f.client.get_accounting_totals(),
+        ));
+    }
+    for outcome in &outcomes[1..] {
+        assert_eq!(*outcome, outcomes[0]);
+    }
+    assert_eq!(outcomes[0].0, 500);
+    assert_eq!(outcomes[0].2, 500);
+}
+
+#[test]
+fn every_path_respects_milestones() {
+    for path in PATHS {
+        let f = fixture();
+        f.client.set_milestones(
+            &f.vault_id,
+            &vec![
+                &f.env,
+                Milestone {
+                    id: 1,
+                    percentage: 25,
+                    is_unlocked: false,
+                },
+            ],
+        );
+        // Time-vesting is complete but no milestone is unlocked yet
+        f.advance(1_000);
+        assert_eq!(f.claim(path, 100), Err(Error::NothingToClaim), "{path:?}");
+
+        f.client.unlock_milestone(&f.vault_id, &1u64);
+        if !matches!(path, Path::Keeper) {
+            assert_eq!(
+                f.claim(path, 251),
+                Err(Error::InsufficientUnlockedTokens),
+                "{path:?}"
+            );
+        }
+        assert_eq!(f.claim(path, 250), Ok(250), "{path:?}");
+    }
+}
+
+#[test]
+fn every_path_unstakes_the_shortfall() {
+    for path in PATHS {
+        let f = fixture();
+        f.client
+            .stake_tokens(&f.vault_id, &900i128, &Address::generate(&f.env));
+        f.advance(400);
+
+        assert_eq!(f.claim(path, 400), Ok(400), "{path:?}");
+        let vault = f.client.get_vault(&f.vault_id);
+        assert_eq!(vault.staked_amount, 600, "{path:?}");
+        assert_eq!(f.client.get_accounting_totals().staked, 600, "{path:?}");
+    }
+}
+
+#[test]
+fn every_path_applies_the_same_guards() {
+    for path in PATHS {
+        let f = fixture();
+        f.advance(500);
+        f.client.freeze_vault(&f.vault_id);
+        assert_eq!(f.claim(path, 100), Err(Error::VaultFrozen), "{path:?}");
+        f.client.unfreeze_vault(&f.vault_id);
+
+        f.client.migrate_liquidity(&Address::generate(&f.env));
+        assert_eq!(
+            f.claim(path, 100),
+            Err(Error::ContractDeprecated),
+            "{path:?}"
+        );
+    }
+}
@@ -0,0 +1,117 @@
+use soroban_sdk::{
+    testutils::{Address as _, Events},
+    token, Address, Env, Event,
+};
+
+use vesting_contracts::{
+    Error, PayoutAddressSet, TokensClaimed, VestingContractClient, EVENT_VERSION,
+};
+
+mod common;
+use common::{advance, create, Setup};
+
+struct Fixture {
+    env: Env,
+    client: VestingContractClient<'static>,
+    token: token::Client<'static>,
+    owner: Address,
+    vault_id: u64,
+}
+
+fn setup() -> Fixture {
+    let Setup {
+        env,
+        client,
+        token: token_addr,
+        ..
+    } = common::setup();
+
+    let owner = Address::generate(&env);
+    let vault_id = create(&client, &owner, 1_000, 0);
+    advance(&env, 1_000);
+
+    let token = token::Client::new(&env, &token_addr);
+    Fixture {
+        env,
+        client,
+        token,
+        owner,
+        vault_id,
+    }
+}
+
+#[test]
+fn claim_to_pays_the_recipient_and_reports_it() {
+    let s = setup();
+    let exchange = Address::generate(&s.env);
+
+    assert_eq!(s.client.claim_to(&s.vault_id, &300i128, &exchange), 300);
+    let claimed = TokensClaimed {
+        vault_id: s.vault_id,
+        claimant: s.owner.clone(),
+        recipient: exchange.clone(),
+        shares: 300,
+        amount: 300,
+        unstaked: 0,
+        released_total: 300,
+        timestamp: s.env.ledger().timestamp(),
+        version: EVENT_VERSION,
+    };
+    assert!(s
+        .env
+        .events()
+        .all()
+        .filter_by_contract(&s.client.address)
+        .events()
+        .contains(&claimed.to_xdr(&s.env, &s.client.address)));
+    assert_eq!(s.token.balance(&exchange), 300);
+    assert_eq!(s.token.balance(&s.owner), 0);
+
+    assert_eq!(
+        s.client
+            .try_claim_to(&s.vault_id, &100i128, &s.client.address),
+        Err(Ok(Error::InvalidRecipient))
+    );
+    assert_eq!(
+        s.client.try_claim_to(&s.vault_id, &800i128, &exchange),
+        Err(Ok(Error::InsufficientUnlockedTokens))
+    );
+}
+
+#[test]
+fn payout_address_redirects_default_claims() {
+    let s = setup();
+    let hot_wallet = Address::generate(&s.env);
+
+    s.client
+        .set_payout_address(&s.owner, &Some(hot_wallet.clone()));
+    let set = PayoutAddressSet {
+        owner: s.owner.clone(),
+        payout: Some(hot_wallet.clone()),
+        version: EVENT_VERSION,
+    };
+    assert!(s
+        .env
+        .events()
+        .all()
+        .filter_by_contract(&s.client.address)
+        .events()
+        .contains(&set.to_xdr(&s.env, &s.client.address)));
+    assert_eq!(
+        s.client.get_payout_address(&s.owner),
+        Some(hot_wallet.clone())
+    );
+
+    s.client.claim_tokens(&s.vault_id, &200i128);
+    s.client.claim_all(&s.owner, &None);
+    assert_eq!(s.token.balance(&hot_wallet), 1_000);
+    assert_eq!(s.token.balance(&s.owner), 0);
+
+    s.client.set_payout_address(&s.owner, &None);
+    assert_eq!(s.client.get_payout_address(&s.owner), None);
+    assert_eq!(
+        s.client
+            .try_set_payout_address(&s.owner, &Some(s.client.address.clone())),
+        Err(Ok(Error::InvalidRecipient))
+    );
+}
@@ -0,0 +1,130 @@
+use soroban_sdk::{testutils::Address as _, vec, Address, Env, Vec};
+
+use vesting_contracts::{BatchCreateData, Error, Milestone, VestingContractClient};
+
+mod common;
+use common::{setup, Setup};
+
+fn create_cohort(env: &Env, client: &VestingContractClient, size: u32) -> Vec<u64> {
+    let now = env.ledger().timestamp();
+    let mut batch = BatchCreateData {
+        recipients: Vec::new(env),
+        amounts: Vec::new(env),
+        start_times: Vec::new(env),
+        end_times: Vec::new(env),
+        keeper_fees: Vec::new(env),
+        step_durations: Vec::new(env),
+    };
+    for _ in 0..size {
+        batch.recipients.push_back(Address::generate(env));
+        batch.amounts.push_back(1_000);
+        batch.start_times.push_back(now);
+        batch.end_times.push_back(now + 1_000);
+        batch.keeper_fees.push_back(0);
+        batch.step_durations.push_back(0);
+    }
+    client.batch_create_vaults_full(&batch)
+}
+
+#[test]
+fn batches_with_mismatched_lists_are_rejected_up_front() {
+    let Setup { env, client, .. } = setup();
+    let now = env.ledger().timestamp();
+    let batch = BatchCreateData {
+        recipients: vec![&env, Address::generate(&env), Address::generate(&env)],
+        amounts: vec![&env, 1_000i128, 1_000i128],
+        start_times: vec![&env, now, now],
+        end_times: vec![&env, now + 1_000],
+        keeper_fees: vec![&env, 0i128, 0i128],
+        step_durations: Vec::new(&env),
+    };
+    assert_eq!(
+        client.try_batch_create_vaults_full(&batch),
+        Err(Ok(Error::InvalidBatch))
+    );
+    assert_eq!(
+        client.try_batch_create_vaults_lazy(&batch),
+        Err(Ok(Error::InvalidBatch))
+    );
+
+    let too_many_steps = BatchCreateData {
+        end_times: vec![&env, now + 1_000, now + 1_000],
+        step_durations: vec![&env, 0u64, 0u64, 0u64],
+        ..batch
+    };
+    assert_eq!(
+        client.try_batch_create_vaults_lazy(&too_many_steps),
+        Err(Ok(Error::InvalidBatch))
+    );
+    assert_eq!(client.get_cohort_count(), 0);
+
+    // Missing step durations still mean linear vesting
+    let linear = BatchCreateData {
+        step_durations: Vec::new(&env),
+        ..too_many_steps
+    };
+    assert_eq!(client.batch_create_vaults_lazy(&linear).len(), 2);
+}
+
+#[test]
+fn batches_are_recorded_as_cohorts() {
+    let Setup { env, client, .. } = setup();
+
+    let first = create_cohort(&env, &client, 3);
+    let second = create_cohort(&env, &client, 2);
+
+    assert_eq!(client.get_cohort_count(), 2);
+    assert_eq!(client.get_cohort_vaults(&1u64, &0u32, &10u32), first);
+    assert_eq!(client.get_cohort_vaults(&2u64, &0u32, &10u32), second);
+    assert_eq!(client.get_vault_cohort(&second.get(0).unwrap()), Some(2));
+    assert_eq!(
+        client.get_cohort_vaults(&1u64, &1u32, &1u32),
+        vec![&env, first.get(1).unwrap()]
+    );
+}
+
+#[test]
+fn cohort_operations_apply_to_every_member() {
+    let Setup { env, client, .. } = setup();
+    let members = create_cohort(&env, &client, 3);
+    let outsider = create_cohort(&env, &client, 1).get(0).unwrap();
+
+    let m0 = members.get(0).unwrap();
+    client.mark_irrevocable(&m0);
+    assert_eq!(client.revoke_cohort(&1u64), 2_000);
+    assert_eq!(client.mark_cohort_irrevocable(&1u64), 2);
+
+    for vault_id in members.iter() {
+        client.set_milestones(
+            &vault_id,
+            &vec![
+                &env,
+                Milestone {
+                    id: 1,
+                    percentage: 100,
+                    is_unlocked: false,
+                },
+            ],
+        );
+    }
+    client.unlock_milestone(&m0, &1u64);
+    assert_eq!(client.unlock_cohort_milestone(&1u64, &1u64), 2);
+
+    assert_eq!(client.freeze_cohort(&1u64, &9u32, &None, &None), 3);
+    for vault_id in members.iter() {
+        assert!(client.is_vault_frozen(&vault_id));
+        assert!(client.is_vault_irrevocable(&vault_id));
+    }
+    assert!(!client.is_vault_frozen(&outsider));
+    assert_eq!(client.get_vault(&outsider).released_amount, 0);
+}
+
+#[test]
+fn unknown_cohort_is_rejected() {
+    let Setup { env: _, client, .. } = setup();
+
+    assert_eq!(
+        client.try_revoke_cohort(&1u64),
+        Err(Ok(Error::CohortNotFound))
+    );
+}
@@ -0,0 +1,78 @@
+// Fixture shared by the integration tests. Each test file compiles its own
+// copy, so helpers a file doesn't use would otherwise warn.
+#![allow(dead_code)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env,
+};
+
+use vesting_contracts::{VestingContract, VestingContractClient};
+
+pub struct Setup {
+    pub env: Env,
+    pub client: VestingContractClient<'static>,
+    pub admin: Address,
+    pub token: Address,
+}
+
+// An initialized contract with its token set and 1,000,000 tokens minted to it.
+// Every auth is mocked.
+pub fn setup() -> Setup {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VestingContract, ());
+    let client = VestingContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin, &1_000_000i128);
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    client.set_token(&token);
+    token::StellarAssetClient::new(&env, &token).mint(&contract_id, &1_000_000i128);
+
+    Setup {
+        env,
+        client,
+        admin,
+        token,
+    }
+}
+
+// A revocable, non-transferable vault of `amount` vesting linearly over 1,000s
+// from now
+pub fn create(
+    client: &VestingContractClient,
+    owner: &Address,
+    amount: i128,
+    keeper_fee: i128,
+) -> u64 {
+    let now = client.env.ledger().timestamp();
+    create_at(client, owner, amount, keeper_fee, now)
+}
+
+// Like `create`, but vesting from `start`
+pub fn create_at(
+    client: &VestingContractClient,
+    owner: &Address,
+    amount: i128,
+    keeper_fee: i128,
+    start: u64,
+) -> u64 {
+    client.create_vault_full(
+        owner,
+        &amount,
+        &start,
+        &(start + 1_000),
+        &keeper_fee,
+        &true,
+        &false,
+        &0u64,
+    )
+}
+
+pub fn advance(env: &Env, seconds: u64) {
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + seconds);
+}
@@ -0,0 +1,58 @@
+use soroban_sdk::{testutils::Address as _, vec, Address, Env};
+
+use vesting_contracts::VestingContractClient;
+
+mod common;
+use common::{setup, Setup};
+
+fn create_vault(env: &Env, client: &VestingContractClient, owner: &Address) -> u64 {
+    let now = env.ledger().timestamp();
+    client.create_vault_full(
+        owner,
+        &1_000i128,
+        &now,
+        &(now + 1_000),
+        &0i128,
+        &true,
+        &true,
+        &0u64,
+    )
+}
+
+#[test]
+fn delegate_index_follows_set_delegate_and_transfers() {
+    let Setup { env, client, .. } = setup();
+    let owner = Address::generate(&env);
+    let delegate = Address::generate(&env);
+    let other = Address::generate(&env);
+    let v1 = create_vault(&env, &client, &owner);
+    let v2 = create_vault(&env, &client, &owner);
+    let v3 = create_vault(&env, &client, &owner);
+
+    client.set_delegate(&v1, &Some(delegate.clone()));
+    client.set_delegate(&v2, &Some(delegate.clone()));
+    client.set_delegate(&v3, &Some(delegate.clone()));
+    assert_eq!(
+        client.get_delegate_vaults(&delegate, &0u32, &10u32),
+        vec![&env, v1, v2, v3]
+    );
+    assert_eq!(
+        client.get_delegate_vaults(&delegate, &1u32, &1u32),
+        vec![&env, v2]
+    );
+
+    client.set_delegate(&v1, &Some(other.clone()));
+    client.set_delegate(&v2, &None);
+    client.transfer_vault(&v3, &Address::generate(&env));
+
+    assert!(client
+        .get_delegate_vaults(&delegate, &0u32, &10u32)
+        .is_empty());
+    assert_eq!(
+        client.get_delegate_vaults(&other, &0u32, &10u32),
+        vec![&env, v1]
+    );
+
+    client.rotate_beneficiary_key(&v1, &Address::generate(&env));
+    assert!(client.get_delegate_vaults(&other, &0u32, &10u32).is_empty());
+}
@@ -0,0 +1,143 @@
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as _, Ledger},
+    Address, Env,
+};
+
+use vesting_contracts::{DelegatePermissions, Error, VestingContractClient};
+
+mod common;
+use common::{create, Setup};
+
+#[contract]
+pub struct MockStaking;
+
+#[contractimpl]
+impl MockStaking {
+    pub fn stake(_env: Env, _vault_id: u64, _amount: i128, _validator: Address) {}
+    pub fn unstake(_env: Env, _vault_id: u64, _amount: i128) {}
+}
+
+// A vault of 1,000 vesting linearly over 1,000s with `delegate` set
+fn setup() -> (Env, VestingContractClient<'static>, u64, Address) {
+    let Setup { env, client, .. } = common::setup();
+    client.set_staking_contract(&env.register(MockStaking, ()));
+
+    let owner = Address::generate(&env);
+    let delegate = Address::generate(&env);
+    let vault_id = create(&client, &owner, 1_000, 0);
+    client.set_delegate(&vault_id, &Some(delegate.clone()));
+    (env, client, vault_id, delegate)
+}
+
+fn advance(env: &Env, seconds: u64) {
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + seconds);
+}
+
+#[test]
+fn claim_cap_resets_each_period_until_expiry() {
+    let (env, client, vault_id, delegate) = setup();
+    let expires_at = env.ledger().timestamp() + 600;
+    client.set_delegate_permissions(
+        &vault_id,
+        &delegate,
+        &Some(DelegatePermissions {
+            can_claim: true,
+            can_stake: false,
+            claim_cap: Some(100),
+            period: 200,
+            expires_at: Some(expires_at),
+        }),
+    );
+    assert_eq!(
+        client.get_delegate_allowance(&vault_id, &delegate),
+        Some(100)
+    );
+
+    advance(&env, 300);
+    assert_eq!(client.claim_as_delegate(&vault_id, &60i128), 60);
+    assert_eq!(
+        client.try_claim_as_delegate(&vault_id, &50i128),
+        Err(Ok(Error::DelegateCapExceeded))
+    );
+    assert_eq!(client.claim_as_delegate(&vault_id, &40i128), 40);
+    assert_eq!(client.get_delegate_allowance(&vault_id, &delegate), Some(0));
+
+    // A new window opens once the previous one lapses
+    advance(&env, 199);
+    assert_eq!(client.get_delegate_allowance(&vault_id, &delegate), Some(0));
+    advance(&env, 1);
+    assert_eq!(
+        client.get_delegate_allowance(&vault_id, &delegate),
+        Some(100)
+    );
+    assert_eq!(client.claim_as_delegate(&vault_id, &100i128), 100);
+
+    advance(&env, 100);
+    assert_eq!(
+        client.try_claim_as_delegate(&vault_id, &10i128),
+        Err(Ok(Error::DelegateExpired))
+    );
+}
+
+#[test]
+fn staking_needs_explicit_permission() {
+    let (env, client, vault_id, delegate) = setup();
+    let validator = Address::generate(&env);
+
+    assert_eq!(
+        client.try_stake_as_delegate(&vault_id, &100i128, &validator),
+        Err(Ok(Error::DelegateNotPermitted))
+    );
+
+    client.set_delegate_permissions(
+        &vault_id,
+        &delegate,
+        &Some(DelegatePermissions {
+            can_claim: false,
+            can_stake: true,
+            claim_cap: None,
+            period: 0,
+            expires_at: None,
+        }),
+    );
+    client.stake_as_delegate(&vault_id, &100i128, &validator);
+    assert_eq!(client.get_vault(&vault_id).staked_amount, 100);
+
+    advance(&env, 1_000);
+    assert_eq!(
+        client.try_claim_as_delegate(&vault_id, &10i128),
+        Err(Ok(Error::DelegateNotPermitted))
+    );
+}
+
+#[test]
+fn permissions_are_dropped_with_the_delegate() {
+    let (env, client, vault_id, delegate) = setup();
+    let stranger = Address::generate(&env);
+    let restricted = DelegatePermissions {
+        can_claim: false,
+        can_stake: false,
+        claim_cap: None,
+        period: 0,
+        expires_at: None,
+    };
+
+    assert_eq!(
+        client.try_set_delegate_permissions(&vault_id, &stranger, &Some(restricted.clone())),
+        Err(Ok(Error::NoDelegate))
+    );
+    client.set_delegate_permissions(&vault_id, &delegate, &Some(restricted.clone()));
+    assert_eq!(
+        client.get_delegate_permissions(&vault_id, &delegate),
+        Some(restricted)
+    );
+
+    client.set_delegate(&vault_id, &None);
+    client.set_delegate(&vault_id, &Some(delegate.clone()));
+    assert_eq!(client.get_delegate_permissions(&vault_id, &delegate), None);
+
+    advance(&env, 1_000);
+    assert_eq!(client.claim_as_delegate(&vault_id, &10i128), 10);
+}
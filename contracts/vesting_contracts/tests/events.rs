@@ -0,0 +1,111 @@
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger},
+    Address, Event, String,
+};
+
+use vesting_contracts::{TokensClaimed, TokensRevoked, VaultCreated, VaultTitleSet, EVENT_VERSION};
+
+mod common;
+use common::{setup, Setup};
+
+#[test]
+fn vault_creation_and_title_events_carry_full_payload() {
+    let Setup { env, client, .. } = setup();
+    let beneficiary = Address::generate(&env);
+
+    let now = env.ledger().timestamp();
+    let vault_id = client.create_vault_full(
+        &beneficiary,
+        &1_000i128,
+        &(now + 100),
+        &(now + 1_000),
+        &5i128,
+        &true,
+        &false,
+        &10u64,
+    );
+    assert_eq!(
+        env.events().all().filter_by_contract(&client.address),
+        std::vec![VaultCreated {
+            vault_id,
+            owner: beneficiary.clone(),
+            total_amount: 1_000,
+            keeper_fee: 5,
+            title: String::from_str(&env, ""),
+            start_time: now + 100,
+            end_time: now + 1_000,
+            cliff_duration: 100,
+            step_duration: 10,
+            creation_time: now,
+            is_initialized: true,
+            is_irrevocable: false,
+            is_transferable: false,
+            version: EVENT_VERSION,
+        }
+        .to_xdr(&env, &client.address)]
+    );
+
+    let title = String::from_str(&env, "Seed round");
+    client.set_vault_title(&vault_id, &title);
+    assert_eq!(
+        env.events().all().filter_by_contract(&client.address),
+        std::vec![VaultTitleSet {
+            vault_id,
+            title,
+            version: EVENT_VERSION,
+        }
+        .to_xdr(&env, &client.address)]
+    );
+}
+
+#[test]
+fn claim_and_revoke_events_report_running_totals() {
+    let Setup { env, client, .. } = setup();
+    let beneficiary = Address::generate(&env);
+
+    let now = env.ledger().timestamp();
+    let vault_id = client.create_vault_full(
+        &beneficiary,
+        &1_000i128,
+        &now,
+        &(now + 1_000),
+        &0i128,
+        &true,
+        &false,
+        &0u64,
+    );
+
+    env.ledger().set_timestamp(now + 500);
+    client.claim_tokens(&vault_id, &200i128);
+    let claimed = TokensClaimed {
+        vault_id,
+        claimant: beneficiary.clone(),
+        recipient: beneficiary.clone(),
+        shares: 200,
+        amount: 200,
+        unstaked: 0,
+        released_total: 200,
+        timestamp: now + 500,
+        version: EVENT_VERSION,
+    };
+    assert!(env
+        .events()
+        .all()
+        .events()
+        .contains(&claimed.to_xdr(&env, &client.address)));
+
+    client.revoke_tokens(&vault_id);
+    let revoked = TokensRevoked {
+        vault_id,
+        beneficiary,
+        amount: 800,
+        released_total: 1_000,
+        timestamp: now + 500,
+        version: EVENT_VERSION,
+    };
+    assert!(env
+        .events()
+        .all()
+        .events()
+        .contains(&revoked.to_xdr(&env, &client.address)));
+}
@@ -0,0 +1,104 @@
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address, BytesN,
+};
+
+use vesting_contracts::{Error, VaultPage};
+
+mod common;
+use common::{create, setup, Setup};
+
+#[test]
+fn freeze_records_reason_reference_and_admin() {
+    let Setup {
+        env, client, admin, ..
+    } = setup();
+    let vault_id = create(&client, &Address::generate(&env), 1_000, 0);
+    let case_file = BytesN::from_array(&env, &[7u8; 32]);
+
+    client.freeze_vault_with_reason(&vault_id, &42u32, &Some(case_file.clone()), &None);
+
+    let record = client.get_freeze_record(&vault_id).unwrap();
+    assert_eq!(record.reason_code, 42);
+    assert_eq!(record.reference_hash, Some(case_file));
+    assert_eq!(record.frozen_by, admin);
+    assert_eq!(record.expires_at, None);
+    assert_eq!(
+        client.list_frozen_vaults(&0, &10),
+        VaultPage {
+            vault_ids: vec![&env, vault_id],
+            next_cursor: None,
+        }
+    );
+
+    client.unfreeze_vault(&vault_id);
+    assert!(!client.is_vault_frozen(&vault_id));
+    assert_eq!(client.get_freeze_record(&vault_id), None);
+    assert!(client.list_frozen_vaults(&0, &10).vault_ids.is_empty());
+}
+
+#[test]
+fn freeze_with_expiry_lapses_automatically() {
+    let Setup { env, client, .. } = setup();
+    let vault_id = create(&client, &Address::generate(&env), 1_000, 0);
+    let other_id = create(&client, &Address::generate(&env), 1_000, 0);
+
+    let expires_at = env.ledger().timestamp() + 500;
+    client.freeze_vault_with_reason(&vault_id, &1u32, &None, &Some(expires_at));
+    client.freeze_vault(&other_id);
+
+    env.ledger().set_timestamp(expires_at - 1);
+    assert!(client.is_vault_frozen(&vault_id));
+    assert!(client.try_claim_tokens(&vault_id, &100i128).is_err());
+
+    env.ledger().set_timestamp(expires_at);
+    assert!(!client.is_vault_frozen(&vault_id));
+    assert_eq!(
+        client.list_frozen_vaults(&0, &10).vault_ids,
+        vec![&env, other_id]
+    );
+    assert_eq!(client.claim_tokens(&vault_id, &100i128), 100i128);
+
+    // Unfreezing the lapsed freeze prunes it from the index
+    assert_eq!(client.get_frozen_vault_count(), 2);
+    client.unfreeze_vault(&vault_id);
+    assert_eq!(client.get_frozen_vault_count(), 1);
+    assert_eq!(
+        client.try_unfreeze_vault(&vault_id),
+        Err(Ok(Error::VaultNotFrozen))
+    );
+    assert_eq!(client.list_frozen_vaults(&1, &10).vault_ids, vec![&env]);
+}
+
+#[test]
+fn frozen_vault_pages_skip_lapsed_freezes() {
+    let Setup { env, client, .. } = setup();
+    let owner = Address::generate(&env);
+    let expires_at = env.ledger().timestamp() + 500;
+    let ids: std::vec::Vec<u64> = (0..4).map(|_| create(&client, &owner, 1_000, 0)).collect();
+    client.freeze_vault_with_reason(&ids[0], &1u32, &None, &Some(expires_at));
+    client.freeze_vault_with_reason(&ids[1], &1u32, &None, &Some(expires_at));
+    client.freeze_vault(&ids[2]);
+    client.freeze_vault(&ids[3]);
+    env.ledger().set_timestamp(expires_at);
+
+    // A full page of active freezes, past the lapsed ones
+    let page = client.list_frozen_vaults(&0, &1);
+    assert_eq!(page.vault_ids, vec![&env, ids[2]]);
+    assert_eq!(page.next_cursor, Some(3));
+    let page = client.list_frozen_vaults(&3, &1);
+    assert_eq!(page.vault_ids, vec![&env, ids[3]]);
+    assert_eq!(page.next_cursor, None);
+}
+
+#[test]
+fn freeze_rejects_past_expiry() {
+    let Setup { env, client, .. } = setup();
+    let vault_id = create(&client, &Address::generate(&env), 1_000, 0);
+
+    let now = env.ledger().timestamp();
+    assert_eq!(
+        client.try_freeze_vault_with_reason(&vault_id, &1u32, &None, &Some(now)),
+        Err(Ok(Error::InvalidFreezeExpiry))
+    );
+}
@@ -0,0 +1,115 @@
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address,
+};
+
+use vesting_contracts::{Error, KeeperFeeConfig};
+
+mod common;
+use common::{create, setup, Setup};
+
+fn config(bps: u32, min_fee: i128, max_fee: i128) -> KeeperFeeConfig {
+    KeeperFeeConfig {
+        bps,
+        min_fee,
+        max_fee,
+    }
+}
+
+#[test]
+fn percentage_fee_is_clamped_and_waived_for_small_claims() {
+    let Setup {
+        env,
+        client,
+        token: token_addr,
+        ..
+    } = setup();
+    let alice = Address::generate(&env);
+    let keeper = Address::generate(&env);
+    let large = create(&client, &alice, 100_000, 0);
+    let medium = create(&client, &alice, 1_000, 0);
+    let small = create(&client, &alice, 5, 0);
+
+    // 1% with a floor of 5 and a ceiling of 200
+    client.set_default_keeper_fee(&Some(config(100, 5, 200)));
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1_000);
+
+    assert_eq!(client.quote_keeper_fee(&large), 200);
+    assert_eq!(client.quote_keeper_fee(&medium), 10);
+    assert_eq!(client.quote_keeper_fee(&small), 0);
+
+    assert_eq!(client.auto_claim(&large, &keeper), 99_800);
+    assert_eq!(client.auto_claim(&medium, &keeper), 990);
+    // The floor would take the whole claim, so it goes through fee-free
+    assert_eq!(client.auto_claim(&small, &keeper), 5);
+
+    let token = token::Client::new(&env, &token_addr);
+    assert_eq!(token.balance(&keeper), 210);
+    assert_eq!(client.get_keeper_fee(&keeper), 210);
+    assert_eq!(client.get_vault(&small).released_amount, 5);
+}
+
+#[test]
+fn vault_override_beats_fixed_fee_and_default() {
+    let Setup { env, client, .. } = setup();
+    let alice = Address::generate(&env);
+    let fixed = create(&client, &alice, 1_000, 50);
+    let overridden = create(&client, &alice, 1_000, 50);
+    let defaulted = create(&client, &alice, 1_000, 0);
+
+    client.set_default_keeper_fee(&Some(config(200, 0, 1_000)));
+    client.set_vault_keeper_fee(&overridden, &Some(config(0, 1, 1)));
+    assert_eq!(
+        client.get_vault_keeper_fee(&overridden),
+        Some(config(0, 1, 1))
+    );
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1_000);
+
+    assert_eq!(client.quote_keeper_fee(&fixed), 50);
+    assert_eq!(client.quote_keeper_fee(&overridden), 1);
+    assert_eq!(client.quote_keeper_fee(&defaulted), 20);
+
+    client.set_vault_keeper_fee(&overridden, &None);
+    assert_eq!(client.quote_keeper_fee(&overridden), 50);
+    client.set_default_keeper_fee(&None);
+    assert_eq!(client.get_default_keeper_fee(), None);
+    assert_eq!(client.quote_keeper_fee(&defaulted), 0);
+}
+
+#[test]
+fn fixed_fee_is_waived_like_configured_fees_and_configs_are_validated() {
+    let Setup {
+        env,
+        client,
+        token: token_addr,
+        ..
+    } = setup();
+    let alice = Address::generate(&env);
+    let keeper = Address::generate(&env);
+    let vault_id = create(&client, &alice, 100, 80);
+    client.set_default_keeper_fee(&Some(config(1_000, 0, 100)));
+    env.ledger().set_timestamp(env.ledger().timestamp() + 500);
+
+    // The fixed fee takes precedence over the default even when it is waived
+    assert_eq!(client.quote_keeper_fee(&vault_id), 0);
+    assert_eq!(client.auto_claim(&vault_id, &keeper), 50);
+    assert_eq!(token::Client::new(&env, &token_addr).balance(&alice), 50);
+    assert_eq!(client.get_keeper_fee(&keeper), 0);
+
+    assert_eq!(
+        client.try_set_default_keeper_fee(&Some(config(10_001, 0, 0))),
+        Err(Ok(Error::InvalidKeeperFee))
+    );
+    assert_eq!(
+        client.try_set_vault_keeper_fee(&vault_id, &Some(config(100, 10, 5))),
+        Err(Ok(Error::InvalidKeeperFee))
+    );
+    assert_eq!(
+        client.try_set_vault_keeper_fee(&vault_id, &Some(config(100, -1, 5))),
+        Err(Ok(Error::InvalidKeeperFee))
+    );
+    assert_eq!(
+        client.try_set_vault_keeper_fee(&999, &Some(config(100, 0, 5))),
+        Err(Ok(Error::VaultNotFound))
+    );
+}
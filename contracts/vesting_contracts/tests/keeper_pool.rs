@@ -0,0 +1,173 @@
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address,
+};
+
+use vesting_contracts::{AutoClaimStatus, Error, KeeperLedger, KeeperPool};
+
+mod common;
+use common::{create, setup, Setup};
+
+#[test]
+fn sponsored_fee_comes_from_pool_and_is_withdrawn_by_keeper() {
+    let Setup {
+        env,
+        client,
+        token: token_addr,
+        ..
+    } = setup();
+    let alice = Address::generate(&env);
+    let keeper = Address::generate(&env);
+    let sponsored = create(&client, &alice, 1_000, 50);
+    let unsponsored = create(&client, &alice, 1_000, 50);
+
+    let (_, _, admin_before) = client.get_contract_state();
+    assert_eq!(client.fund_keeper_pool(&200i128), 200);
+    let (_, _, admin_after) = client.get_contract_state();
+    assert_eq!(admin_before - admin_after, 200);
+
+    client.set_keeper_sponsorship(&sponsored, &true);
+    assert!(client.is_keeper_sponsored(&sponsored));
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1_000);
+
+    // The beneficiary keeps the whole claim; the fee is credited, not paid
+    assert_eq!(client.auto_claim(&sponsored, &keeper), 1_000);
+    assert_eq!(client.auto_claim(&unsponsored, &keeper), 950);
+
+    let token = token::Client::new(&env, &token_addr);
+    assert_eq!(token.balance(&alice), 1_950);
+    assert_eq!(token.balance(&keeper), 50);
+    assert_eq!(
+        client.get_keeper_pool(),
+        KeeperPool {
+            balance: 150,
+            owed: 50,
+        }
+    );
+    assert_eq!(
+        client.get_keeper_ledger(&keeper),
+        KeeperLedger {
+            deducted: 50,
+            sponsored: 50,
+            withdrawn: 0,
+            claims: 2,
+        }
+    );
+    assert_eq!(client.get_keeper_fee(&keeper), 100);
+
+    assert_eq!(client.withdraw_keeper_fees(&keeper), 50);
+    assert_eq!(token.balance(&keeper), 100);
+    assert_eq!(client.get_keeper_pool().owed, 0);
+    assert_eq!(client.get_keeper_ledger(&keeper).withdrawn, 50);
+    assert_eq!(
+        client.try_withdraw_keeper_fees(&keeper),
+        Err(Ok(Error::NothingToWithdraw))
+    );
+
+    assert_eq!(client.withdraw_keeper_pool(&150i128), 0);
+    let (_, _, admin_final) = client.get_contract_state();
+    assert_eq!(admin_final, admin_after + 150);
+}
+
+#[test]
+fn sponsored_fee_is_debited_in_tokens_at_the_current_share_value() {
+    let Setup {
+        env,
+        client,
+        token: token_addr,
+        ..
+    } = setup();
+    let alice = Address::generate(&env);
+    let keeper = Address::generate(&env);
+    let vault_id = create(&client, &alice, 1_000, 50);
+    client.set_keeper_sponsorship(&vault_id, &true);
+    client.fund_keeper_pool(&150i128);
+    // Yield doubles the value of every share
+    token::StellarAssetClient::new(&env, &token_addr).mint(&client.address, &1_000i128);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1_000);
+
+    // 50 shares are now worth 100 tokens
+    client.withdraw_keeper_pool(&60i128);
+    assert_eq!(
+        client.try_auto_claim(&vault_id, &keeper),
+        Err(Ok(Error::KeeperPoolInsufficient))
+    );
+    client.fund_keeper_pool(&60i128);
+
+    assert_eq!(client.auto_claim(&vault_id, &keeper), 1_000);
+    assert_eq!(token::Client::new(&env, &token_addr).balance(&alice), 2_000);
+    assert_eq!(
+        client.get_keeper_pool(),
+        KeeperPool {
+            balance: 50,
+            owed: 100,
+        }
+    );
+    assert_eq!(client.get_keeper_ledger(&keeper).sponsored, 100);
+    assert_eq!(client.withdraw_keeper_fees(&keeper), 100);
+}
+
+#[test]
+fn deducted_and_sponsored_fees_are_both_counted_in_tokens() {
+    let Setup {
+        env,
+        client,
+        token: token_addr,
+        ..
+    } = setup();
+    let alice = Address::generate(&env);
+    let keeper = Address::generate(&env);
+    let sponsored = create(&client, &alice, 1_000, 50);
+    let unsponsored = create(&client, &alice, 1_000, 50);
+    client.set_keeper_sponsorship(&sponsored, &true);
+    client.fund_keeper_pool(&500i128);
+    // Yield doubles the value of every share
+    token::StellarAssetClient::new(&env, &token_addr).mint(&client.address, &2_000i128);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1_000);
+
+    client.auto_claim(&unsponsored, &keeper);
+    client.auto_claim(&sponsored, &keeper);
+
+    let token = token::Client::new(&env, &token_addr);
+    assert_eq!(token.balance(&keeper), 100);
+    let ledger = client.get_keeper_ledger(&keeper);
+    assert_eq!(ledger.deducted, 100);
+    assert_eq!(ledger.sponsored, 100);
+    assert_eq!(client.get_keeper_fee(&keeper), 200);
+}
+
+#[test]
+fn empty_pool_blocks_sponsored_claims_but_not_batches() {
+    let Setup { env, client, .. } = setup();
+    let alice = Address::generate(&env);
+    let keeper = Address::generate(&env);
+    let sponsored = create(&client, &alice, 1_000, 50);
+    let plain = create(&client, &alice, 1_000, 50);
+    client.set_keeper_sponsorship(&sponsored, &true);
+    client.fund_keeper_pool(&10i128);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1_000);
+
+    assert_eq!(
+        client.try_auto_claim(&sponsored, &keeper),
+        Err(Ok(Error::KeeperPoolInsufficient))
+    );
+    let results = client.batch_auto_claim(&vec![&env, sponsored, plain], &keeper);
+    assert_eq!(
+        results.get(0).unwrap().status,
+        AutoClaimStatus::PoolExhausted
+    );
+    assert_eq!(results.get(1).unwrap().status, AutoClaimStatus::Claimed);
+
+    assert_eq!(
+        client.try_withdraw_keeper_pool(&11i128),
+        Err(Ok(Error::KeeperPoolInsufficient))
+    );
+    assert_eq!(
+        client.try_fund_keeper_pool(&10_000_000i128),
+        Err(Ok(Error::InsufficientAdminBalance))
+    );
+
+    // Opting back out deducts the fee from the claim again
+    client.set_keeper_sponsorship(&sponsored, &false);
+    assert_eq!(client.auto_claim(&sponsored, &keeper), 950);
+}
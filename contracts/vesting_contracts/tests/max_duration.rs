@@ -1,6 +1,8 @@
 use soroban_sdk::{testutils::Address as _, vec, Address, Env};
 
-use vesting_contracts::{BatchCreateData, VestingContract, VestingContractClient, MAX_DURATION};
+use vesting_contracts::{
+    BatchCreateData, Error, VestingContract, VestingContractClient, MAX_DURATION,
+};
 
 fn setup(env: &Env) -> (VestingContractClient<'static>, Address) {
     env.mock_all_auths();
@@ -36,7 +38,6 @@ fn create_vault_full_allows_max_duration() {
 }
 
 #[test]
-#[should_panic(expected = "duration exceeds MAX_DURATION")]
 fn create_vault_full_rejects_over_max_duration() {
     let env = Env::default();
     let (client, _admin) = setup(&env);
@@ -45,20 +46,22 @@ fn create_vault_full_rejects_over_max_duration() {
     let start = env.ledger().timestamp();
     let end = start + MAX_DURATION + 1;
 
-    client.create_vault_full(
-        &beneficiary,
-        &1_000i128,
-        &start,
-        &end,
-        &0i128,
-        &true,
-        &false,
-        &0u64,
+    assert_eq!(
+        client.try_create_vault_full(
+            &beneficiary,
+            &1_000i128,
+            &start,
+            &end,
+            &0i128,
+            &true,
+            &false,
+            &0u64,
+        ),
+        Err(Ok(Error::DurationTooLong))
     );
 }
 
 #[test]
-#[should_panic(expected = "duration exceeds MAX_DURATION")]
 fn create_vault_lazy_rejects_over_max_duration() {
     let env = Env::default();
     let (client, _admin) = setup(&env);
@@ -67,20 +70,22 @@ fn create_vault_lazy_rejects_over_max_duration() {
     let start = env.ledger().timestamp();
     let end = start + MAX_DURATION + 1;
 
-    client.create_vault_lazy(
-        &beneficiary,
-        &1_000i128,
-        &start,
-        &end,
-        &0i128,
-        &true,
-        &false,
-        &0u64,
+    assert_eq!(
+        client.try_create_vault_lazy(
+            &beneficiary,
+            &1_000i128,
+            &start,
+            &end,
+            &0i128,
+            &true,
+            &false,
+            &0u64,
+        ),
+        Err(Ok(Error::DurationTooLong))
     );
 }
 
 #[test]
-#[should_panic(expected = "duration exceeds MAX_DURATION")]
 fn batch_create_vaults_rejects_over_max_duration() {
     let env = Env::default();
     let (client, _admin) = setup(&env);
@@ -98,5 +103,8 @@ fn batch_create_vaults_rejects_over_max_duration() {
         step_durations: vec![&env, 0u64],
     };
 
-    client.batch_create_vaults_lazy(&batch);
+    assert_eq!(
+        client.try_batch_create_vaults_lazy(&batch),
+        Err(Ok(Error::DurationTooLong))
+    );
 }
@@ -0,0 +1,176 @@
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as _, Ledger},
+    vec, Address, Env, Vec,
+};
+
+use vesting_contracts::{DelegatePermissions, Error, VestingContractClient};
+
+mod common;
+use common::{create, Setup};
+
+#[contract]
+pub struct MockStaking;
+
+#[contractimpl]
+impl MockStaking {
+    pub fn stake(_env: Env, _vault_id: u64, _amount: i128, _validator: Address) {}
+    pub fn unstake(_env: Env, _vault_id: u64, _amount: i128) {}
+}
+
+// A vault of 1,000 vesting linearly over 1,000s, with no delegates
+fn setup() -> (Env, VestingContractClient<'static>, u64) {
+    let Setup { env, client, .. } = common::setup();
+    client.set_staking_contract(&env.register(MockStaking, ()));
+
+    let owner = Address::generate(&env);
+    let vault_id = create(&client, &owner, 1_000, 0);
+    (env, client, vault_id)
+}
+
+fn permissions(can_claim: bool, can_stake: bool, claim_cap: Option<i128>) -> DelegatePermissions {
+    DelegatePermissions {
+        can_claim,
+        can_stake,
+        claim_cap,
+        period: 0,
+        expires_at: None,
+    }
+}
+
+#[test]
+fn each_delegate_acts_under_its_own_permissions() {
+    let (env, client, vault_id) = setup();
+    let accountant = Address::generate(&env);
+    let custodian = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    let validator = Address::generate(&env);
+
+    client.add_delegate(
+        &vault_id,
+        &accountant,
+        &Some(permissions(true, false, Some(100))),
+    );
+    client.add_delegate(&vault_id, &custodian, &Some(permissions(false, true, None)));
+    assert_eq!(
+        client.get_delegates(&vault_id),
+        vec![&env, accountant.clone(), custodian.clone()]
+    );
+    assert_eq!(
+        client.get_vault(&vault_id).delegate,
+        Some(accountant.clone())
+    );
+    env.ledger().set_timestamp(env.ledger().timestamp() + 500);
+
+    assert_eq!(client.delegate_claim(&vault_id, &accountant, &100i128), 100);
+    assert_eq!(
+        client.try_delegate_claim(&vault_id, &accountant, &1i128),
+        Err(Ok(Error::DelegateCapExceeded))
+    );
+    assert_eq!(
+        client.try_delegate_claim(&vault_id, &custodian, &10i128),
+        Err(Ok(Error::DelegateNotPermitted))
+    );
+    assert_eq!(
+        client.try_delegate_claim(&vault_id, &stranger, &10i128),
+        Err(Ok(Error::NoDelegate))
+    );
+
+    // The single-delegate entry points act as the primary delegate
+    assert_eq!(
+        client.try_stake_as_delegate(&vault_id, &50i128, &validator),
+        Err(Ok(Error::DelegateNotPermitted))
+    );
+    client.delegate_stake(&vault_id, &custodian, &50i128, &validator);
+    assert_eq!(client.get_vault(&vault_id).staked_amount, 50);
+}
+
+#[test]
+fn removing_the_primary_promotes_the_next_delegate() {
+    let (env, client, vault_id) = setup();
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    client.set_delegate(&vault_id, &Some(first.clone()));
+    client.add_delegate(&vault_id, &second, &None);
+
+    assert_eq!(
+        client.try_add_delegate(&vault_id, &second, &None),
+        Err(Ok(Error::DelegateAlreadyAdded))
+    );
+
+    client.remove_delegate(&vault_id, &first);
+    assert_eq!(client.get_delegates(&vault_id), vec![&env, second.clone()]);
+    assert_eq!(client.get_vault(&vault_id).delegate, Some(second.clone()));
+    assert_eq!(
+        client.try_remove_delegate(&vault_id, &first),
+        Err(Ok(Error::NoDelegate))
+    );
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1_000);
+    assert_eq!(client.claim_as_delegate(&vault_id, &10i128), 10);
+
+    client.remove_delegate(&vault_id, &second);
+    assert_eq!(client.get_delegates(&vault_id), Vec::new(&env));
+    assert_eq!(
+        client.try_claim_as_delegate(&vault_id, &10i128),
+        Err(Ok(Error::NoDelegate))
+    );
+}
+
+#[test]
+fn set_delegate_replaces_the_whole_set_and_the_cap_is_enforced() {
+    let (env, client, vault_id) = setup();
+    let mut delegates = Vec::new(&env);
+    for _ in 0..10 {
+        let delegate = Address::generate(&env);
+        client.add_delegate(&vault_id, &delegate, &None);
+        delegates.push_back(delegate);
+    }
+    assert_eq!(
+        client.try_add_delegate(&vault_id, &Address::generate(&env), &None),
+        Err(Ok(Error::TooManyDelegates))
+    );
+
+    // Keeping an existing delegate keeps its permissions
+    let kept = delegates.get(3).unwrap();
+    let restricted = permissions(true, false, Some(5));
+    client.set_delegate_permissions(&vault_id, &kept, &Some(restricted.clone()));
+    client.set_delegate(&vault_id, &Some(kept.clone()));
+    assert_eq!(client.get_delegates(&vault_id), vec![&env, kept.clone()]);
+    assert_eq!(
+        client.get_delegate_permissions(&vault_id, &kept),
+        Some(restricted)
+    );
+    assert_eq!(
+        client.get_delegate_vaults(&delegates.get(0).unwrap(), &0u32, &10u32),
+        Vec::new(&env)
+    );
+
+    client.add_delegate(&vault_id, &delegates.get(0).unwrap(), &None);
+    let new_owner = Address::generate(&env);
+    client.rotate_beneficiary_key(&vault_id, &new_owner);
+    assert_eq!(client.get_delegates(&vault_id), Vec::new(&env));
+    assert_eq!(client.get_vault(&vault_id).delegate, None);
+}
+
+#[test]
+fn admin_transfer_clears_every_delegate() {
+    let (env, client, vault_id) = setup();
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    client.add_delegate(&vault_id, &first, &None);
+    client.add_delegate(&vault_id, &second, &None);
+
+    client.transfer_beneficiary(&vault_id, &Address::generate(&env));
+    assert_eq!(client.get_delegates(&vault_id), Vec::new(&env));
+    assert_eq!(client.get_vault(&vault_id).delegate, None);
+    assert_eq!(
+        client.get_delegate_vaults(&second, &0u32, &10u32),
+        Vec::new(&env)
+    );
+    env.ledger().set_timestamp(env.ledger().timestamp() + 500);
+    assert_eq!(
+        client.try_delegate_claim(&vault_id, &second, &10i128),
+        Err(Ok(Error::NoDelegate))
+    );
+}
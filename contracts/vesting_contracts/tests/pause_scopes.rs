@@ -0,0 +1,141 @@
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address,
+};
+
+use vesting_contracts::{Error, PauseMatrix, PauseScope};
+
+mod common;
+use common::{create, setup, Setup};
+
+#[test]
+fn pause_matrix_defaults_to_all_active() {
+    let Setup { env: _, client, .. } = setup();
+
+    assert_eq!(
+        client.get_pause_matrix(),
+        PauseMatrix {
+            global: false,
+            claims: false,
+            vault_creation: false,
+            staking: false,
+            transfers: false,
+            keeper: false,
+        }
+    );
+}
+
+#[test]
+fn pausing_creation_does_not_halt_claims() {
+    let Setup { env, client, .. } = setup();
+    let beneficiary = Address::generate(&env);
+    let vault_id = create(&client, &beneficiary, 1_000, 0);
+
+    client.set_scope_paused(&PauseScope::VaultCreation, &true);
+    assert!(client.is_scope_paused(&PauseScope::VaultCreation));
+    assert!(!client.is_scope_paused(&PauseScope::Claims));
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1_000);
+    assert_eq!(client.claim_tokens(&vault_id, &100i128), 100i128);
+
+    let now = env.ledger().timestamp();
+    let result = client.try_create_vault_full(
+        &beneficiary,
+        &1_000i128,
+        &now,
+        &(now + 1_000),
+        &0i128,
+        &true,
+        &false,
+        &0u64,
+    );
+    assert!(result.is_err());
+
+    client.set_scope_paused(&PauseScope::VaultCreation, &false);
+    create(&client, &beneficiary, 1_000, 0);
+}
+
+#[test]
+fn paused_claims_reject_claim_tokens() {
+    let Setup { env, client, .. } = setup();
+    let beneficiary = Address::generate(&env);
+    let vault_id = create(&client, &beneficiary, 1_000, 0);
+
+    client.set_scope_paused(&PauseScope::Claims, &true);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1_000);
+    assert_eq!(
+        client.try_claim_tokens(&vault_id, &100i128),
+        Err(Ok(Error::ClaimsPaused))
+    );
+}
+
+#[test]
+fn paused_transfers_reject_transfer_vault() {
+    let Setup { env, client, .. } = setup();
+    let beneficiary = Address::generate(&env);
+    let vault_id = create(&client, &beneficiary, 1_000, 0);
+
+    client.set_scope_paused(&PauseScope::Transfers, &true);
+    assert_eq!(
+        client.try_transfer_vault(&vault_id, &Address::generate(&env)),
+        Err(Ok(Error::TransfersPaused))
+    );
+}
+
+#[test]
+fn global_pause_halts_withdrawal_scopes_only() {
+    let Setup { env: _, client, .. } = setup();
+
+    client.toggle_pause();
+    let matrix = client.get_pause_matrix();
+    assert!(matrix.global);
+    assert!(matrix.claims);
+    assert!(matrix.keeper);
+    assert!(!matrix.vault_creation);
+    assert!(!matrix.staking);
+    assert!(!matrix.transfers);
+}
+
+#[test]
+fn time_boxed_pause_lapses_and_claims_resume() {
+    let Setup { env, client, .. } = setup();
+    let beneficiary = Address::generate(&env);
+    let vault_id = create(&client, &beneficiary, 1_000, 0);
+
+    let now = env.ledger().timestamp();
+    let expires_at = client.pause_with_expiry(&3_600u64);
+    assert_eq!(expires_at, now + 3_600);
+    assert!(client.is_paused());
+    assert_eq!(client.get_pause_expiry(), Some(expires_at));
+
+    env.ledger().set_timestamp(expires_at - 1);
+    assert!(client.try_claim_tokens(&vault_id, &100i128).is_err());
+
+    env.ledger().set_timestamp(expires_at);
+    assert!(!client.is_paused());
+    assert_eq!(client.get_pause_expiry(), None);
+    assert_eq!(client.claim_tokens(&vault_id, &100i128), 100i128);
+}
+
+#[test]
+fn renewing_time_boxed_pause_extends_expiry() {
+    let Setup { env, client, .. } = setup();
+
+    let first = client.pause_with_expiry(&3_600u64);
+    env.ledger().set_timestamp(first - 10);
+    let renewed = client.pause_with_expiry(&3_600u64);
+    assert!(renewed > first);
+
+    env.ledger().set_timestamp(first + 1);
+    assert!(client.is_paused());
+}
+
+#[test]
+fn time_boxed_pause_rejects_duration_over_max() {
+    let Setup { env: _, client, .. } = setup();
+
+    assert_eq!(
+        client.try_pause_with_expiry(&(vesting_contracts::MAX_PAUSE_DURATION + 1)),
+        Err(Ok(Error::PauseDurationTooLong))
+    );
+}
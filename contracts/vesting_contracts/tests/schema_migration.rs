@@ -0,0 +1,236 @@
+use soroban_sdk::{
+    contracttype, testutils::Address as _, vec, Address, IntoVal, Map, String, Symbol, Val,
+};
+
+use vesting_contracts::{
+    AccountingTotals, DataKey, Error, Milestone, TotalsRecount, SCHEMA_VERSION,
+};
+
+mod common;
+use common::{advance, create, setup, Setup};
+
+// Schema 1 vault layout, before delegates, staking, freezes and titles were added
+#[contracttype]
+#[derive(Clone)]
+pub struct VaultV1 {
+    pub total_amount: i128,
+    pub released_amount: i128,
+    pub keeper_fee: i128,
+    pub owner: Address,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub creation_time: u64,
+    pub step_duration: u64,
+    pub is_initialized: bool,
+    pub is_irrevocable: bool,
+    pub is_transferable: bool,
+}
+
+#[test]
+fn schema_one_records_in_instance_storage_move_on_first_access() {
+    let Setup { env, client, .. } = setup();
+    let contract_id = client.address.clone();
+    let owner = Address::generate(&env);
+    let keeper = Address::generate(&env);
+    let now = env.ledger().timestamp();
+
+    // Schema 1 kept vaults, the owner index, milestones and keeper fees in instance storage
+    env.as_contract(&contract_id, || {
+        let legacy = VaultV1 {
+            total_amount: 1_000,
+            released_amount: 0,
+            keeper_fee: 0,
+            owner: owner.clone(),
+            start_time: now,
+            end_time: now + 1_000,
+            creation_time: now,
+            step_duration: 0,
+            is_initialized: true,
+            is_irrevocable: false,
+            is_transferable: false,
+        };
+        let instance = env.storage().instance();
+        instance.set(&DataKey::VaultData(1), &legacy);
+        instance.set(&DataKey::UserVaults(owner.clone()), &vec![&env, 1u64]);
+        instance.set(
+            &DataKey::VaultMilestones(1),
+            &vec![
+                &env,
+                Milestone {
+                    id: 1,
+                    percentage: 100,
+                    is_unlocked: true,
+                },
+            ],
+        );
+        let mut fees: Map<Address, i128> = Map::new(&env);
+        fees.set(keeper.clone(), 25);
+        instance.set(&vec![&env, Symbol::new(&env, "KeeperFees")], &fees);
+        instance.set(&DataKey::VaultCount, &1u64);
+        instance.set(&DataKey::TotalShares, &1_000i128);
+    });
+
+    // Views that only read a flag leave the record where it is
+    assert!(!client.is_vault_frozen(&1u64));
+    env.as_contract(&contract_id, || {
+        assert!(env.storage().instance().has(&DataKey::VaultData(1)));
+    });
+
+    assert_eq!(client.get_user_vaults(&owner), vec![&env, 1u64]);
+    assert_eq!(client.get_keeper_fee(&keeper), 25);
+    let vault = client.get_vault(&1u64);
+    assert_eq!(vault.owner, owner);
+    assert_eq!(vault.total_amount, 1_000);
+    assert_eq!(vault.staked_amount, 0);
+    assert_eq!(vault.delegate, None);
+    assert!(!vault.is_frozen);
+
+    env.as_contract(&contract_id, || {
+        for key in [
+            DataKey::VaultData(1),
+            DataKey::UserVaults(owner.clone()),
+            DataKey::VaultMilestones(1),
+        ] {
+            assert!(!env.storage().instance().has(&key));
+            assert!(env.storage().persistent().has(&key));
+        }
+    });
+
+    // Milestones moved with the vault, so the full amount is unlocked
+    assert_eq!(client.claim_tokens(&1u64, &1_000i128), 1_000);
+}
+
+#[test]
+fn intermediate_layout_keeps_present_fields_and_defaults_the_rest() {
+    let Setup { env, client, .. } = setup();
+    let contract_id = client.address.clone();
+    let owner = Address::generate(&env);
+    let delegate = Address::generate(&env);
+    let now = env.ledger().timestamp();
+
+    // Staking and delegates but no title or freeze flag
+    env.as_contract(&contract_id, || {
+        let mut raw: Map<Symbol, Val> = Map::new(&env);
+        raw.set(Symbol::new(&env, "total_amount"), 1_000i128.into_val(&env));
+        raw.set(Symbol::new(&env, "released_amount"), 100i128.into_val(&env));
+        raw.set(Symbol::new(&env, "keeper_fee"), 5i128.into_val(&env));
+        raw.set(Symbol::new(&env, "staked_amount"), 0i128.into_val(&env));
+        raw.set(Symbol::new(&env, "owner"), owner.into_val(&env));
+        raw.set(
+            Symbol::new(&env, "delegate"),
+            Some(delegate.clone()).into_val(&env),
+        );
+        raw.set(Symbol::new(&env, "start_time"), now.into_val(&env));
+        raw.set(Symbol::new(&env, "end_time"), (now + 1_000).into_val(&env));
+        raw.set(Symbol::new(&env, "creation_time"), now.into_val(&env));
+        raw.set(Symbol::new(&env, "step_duration"), 0u64.into_val(&env));
+        raw.set(Symbol::new(&env, "is_initialized"), true.into_val(&env));
+        raw.set(Symbol::new(&env, "is_irrevocable"), false.into_val(&env));
+        raw.set(Symbol::new(&env, "is_transferable"), true.into_val(&env));
+        env.storage().instance().set(&DataKey::VaultData(1), &raw);
+        env.storage().instance().set(&DataKey::VaultCount, &1u64);
+    });
+
+    let vault = client.get_vault(&1u64);
+    assert_eq!(vault.released_amount, 100);
+    assert_eq!(vault.keeper_fee, 5);
+    assert_eq!(vault.delegate, Some(delegate));
+    assert!(vault.is_transferable);
+    assert_eq!(vault.title, String::from_str(&env, ""));
+    assert!(!vault.is_frozen);
+}
+
+#[test]
+fn migrate_bumps_legacy_schema_version_once() {
+    let Setup { env, client, .. } = setup();
+    let contract_id = client.address.clone();
+    assert_eq!(client.get_schema_version(), SCHEMA_VERSION);
+    assert!(client.try_migrate().is_err());
+
+    env.as_contract(&contract_id, || {
+        env.storage().instance().remove(&DataKey::SchemaVersion);
+    });
+    assert_eq!(client.get_schema_version(), 1);
+    assert_eq!(client.migrate(), SCHEMA_VERSION);
+    assert_eq!(client.get_schema_version(), SCHEMA_VERSION);
+}
+
+#[test]
+fn initialize_cannot_be_called_again_to_take_over_the_admin() {
+    let Setup {
+        env, client, admin, ..
+    } = setup();
+    let attacker = Address::generate(&env);
+
+    assert_eq!(
+        client.try_initialize(&attacker, &1i128),
+        Err(Ok(Error::AlreadyInitialized))
+    );
+    assert_eq!(client.get_admin(), admin);
+}
+
+#[test]
+fn migrating_from_schema_one_recounts_totals_before_rescue() {
+    let Setup {
+        env, client, admin, ..
+    } = setup();
+    let contract_id = client.address.clone();
+    let owner = Address::generate(&env);
+    for _ in 0..3 {
+        create(&client, &owner, 1_000, 0);
+    }
+    advance(&env, 500);
+    client.claim_tokens(&1u64, &200i128);
+
+    // Schema 1 never kept released or revoked totals
+    env.as_contract(&contract_id, || {
+        let instance = env.storage().instance();
+        instance.remove(&DataKey::SchemaVersion);
+        instance.remove(&DataKey::TotalReleased);
+        instance.remove(&DataKey::TotalRevoked);
+    });
+    client.migrate();
+    assert_eq!(
+        client.get_totals_recount(),
+        Some(TotalsRecount {
+            next_vault_id: 1,
+            last_vault_id: 3,
+        })
+    );
+
+    let other = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    client.add_to_whitelist(&other);
+    assert_eq!(
+        client.try_rescue_unallocated_tokens(&other),
+        Err(Ok(Error::MigrationPending))
+    );
+    assert_eq!(
+        client.try_claim_tokens(&2u64, &100i128),
+        Err(Ok(Error::MigrationPending))
+    );
+    // Vaults created after the migration count themselves
+    let fresh = create(&client, &owner, 500, 0);
+
+    assert_eq!(client.recount_totals(&2u32), Some(3));
+    client.claim_tokens(&2u64, &100i128);
+    assert_eq!(client.recount_totals(&2u32), None);
+    assert_eq!(client.get_totals_recount(), None);
+    assert_eq!(client.recount_totals(&2u32), None);
+
+    assert_eq!(
+        client.get_accounting_totals(),
+        AccountingTotals {
+            locked: 3_000 - 300 + 500,
+            released: 300,
+            revoked: 0,
+            staked: 0,
+        }
+    );
+    assert_eq!(fresh, 4);
+    assert_eq!(
+        client.try_rescue_unallocated_tokens(&other),
+        Err(Ok(Error::NoUnallocatedTokens))
+    );
+}
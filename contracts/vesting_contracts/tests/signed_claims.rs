@@ -0,0 +1,193 @@
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token,
+    xdr::ToXdr,
+    Address, BytesN, Env,
+};
+
+use vesting_contracts::{Error, SignedClaim, VestingContractClient};
+
+mod common;
+use common::Setup;
+
+// A vault of 1,000 vesting linearly over 1,000s whose owner registered `key`
+fn setup() -> (
+    Env,
+    VestingContractClient<'static>,
+    Address,
+    u64,
+    SigningKey,
+) {
+    let Setup {
+        env,
+        client,
+        token: token_addr,
+        ..
+    } = common::setup();
+
+    let owner = Address::generate(&env);
+    let now = env.ledger().timestamp();
+    let vault_id = client.create_vault_full(
+        &owner,
+        &1_000i128,
+        &now,
+        &(now + 1_000),
+        &0i128,
+        &true,
+        &true,
+        &0u64,
+    );
+    let key = SigningKey::from_bytes(&[7u8; 32]);
+    client.set_claim_signer(
+        &vault_id,
+        &Some(BytesN::from_array(&env, key.verifying_key().as_bytes())),
+    );
+    (env, client, token_addr, vault_id, key)
+}
+
+fn signed(
+    client: &VestingContractClient,
+    key: &SigningKey,
+    vault_id: u64,
+    nonce: u64,
+    recipient: &Address,
+    relayer_fee: i128,
+) -> (SignedClaim, BytesN<64>) {
+    let claim = SignedClaim {
+        vault_id,
+        amount: 100,
+        nonce,
+        expires_at: client.env.ledger().timestamp() + 60,
+        recipient: recipient.clone(),
+        relayer_fee,
+    };
+    let message = (client.address.clone(), claim.clone()).to_xdr(&client.env);
+    let bytes: std::vec::Vec<u8> = message.iter().collect();
+    let signature = BytesN::from_array(&client.env, &key.sign(&bytes).to_bytes());
+    (claim, signature)
+}
+
+#[test]
+fn relayer_submits_signed_claim_and_earns_fee() {
+    let (env, client, token_addr, vault_id, key) = setup();
+    let recipient = Address::generate(&env);
+    let relayer = Address::generate(&env);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 500);
+
+    let (claim, signature) = signed(&client, &key, vault_id, 0, &recipient, 5);
+    assert_eq!(
+        client.claim_with_signature(&claim, &signature, &relayer),
+        95
+    );
+
+    let token = token::Client::new(&env, &token_addr);
+    assert_eq!(token.balance(&recipient), 95);
+    assert_eq!(token.balance(&relayer), 5);
+    assert_eq!(client.get_vault(&vault_id).released_amount, 100);
+    assert_eq!(client.get_claim_nonce(&vault_id), 1);
+
+    // The same signature can't be replayed
+    assert_eq!(
+        client.try_claim_with_signature(&claim, &signature, &relayer),
+        Err(Ok(Error::InvalidNonce))
+    );
+    let (next, signature) = signed(&client, &key, vault_id, 1, &recipient, 0);
+    assert_eq!(
+        client.claim_with_signature(&next, &signature, &relayer),
+        100
+    );
+}
+
+#[test]
+fn expired_tampered_and_unregistered_claims_are_rejected() {
+    let (env, client, _, vault_id, key) = setup();
+    let recipient = Address::generate(&env);
+    let relayer = Address::generate(&env);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 500);
+
+    let (claim, signature) = signed(&client, &key, vault_id, 0, &recipient, 100);
+    assert_eq!(
+        client.try_claim_with_signature(&claim, &signature, &relayer),
+        Err(Ok(Error::InsufficientClaimableForFee))
+    );
+    assert_eq!(client.get_claim_nonce(&vault_id), 0);
+
+    let (claim, signature) = signed(&client, &key, vault_id, 0, &recipient, 0);
+    let tampered = SignedClaim {
+        recipient: relayer.clone(),
+        ..claim.clone()
+    };
+    // A signature that doesn't verify aborts the call instead of returning an error
+    assert!(matches!(
+        client.try_claim_with_signature(&tampered, &signature, &relayer),
+        Err(Err(_))
+    ));
+    assert_eq!(client.get_claim_nonce(&vault_id), 0);
+
+    env.ledger().set_timestamp(claim.expires_at);
+    assert_eq!(
+        client.try_claim_with_signature(&claim, &signature, &relayer),
+        Err(Ok(Error::SignedClaimExpired))
+    );
+
+    client.transfer_vault(&vault_id, &Address::generate(&env));
+    assert_eq!(client.get_claim_signer(&vault_id), None);
+    let (claim, signature) = signed(&client, &key, vault_id, 0, &recipient, 0);
+    assert_eq!(
+        client.try_claim_with_signature(&claim, &signature, &relayer),
+        Err(Ok(Error::NoClaimSigner))
+    );
+}
+
+#[test]
+fn typed_checks_run_before_the_signature_is_verified() {
+    let (env, client, _, vault_id, key) = setup();
+    let recipient = Address::generate(&env);
+    let relayer = Address::generate(&env);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 500);
+
+    let (claim, _) = signed(&client, &key, vault_id, 0, &recipient, 0);
+    let forged = BytesN::from_array(&env, &[1u8; 64]);
+    let stale = SignedClaim {
+        nonce: 3,
+        ..claim.clone()
+    };
+    assert_eq!(
+        client.try_claim_with_signature(&stale, &forged, &relayer),
+        Err(Ok(Error::InvalidNonce))
+    );
+    let empty = SignedClaim {
+        amount: 0,
+        ..claim.clone()
+    };
+    assert_eq!(
+        client.try_claim_with_signature(&empty, &forged, &relayer),
+        Err(Ok(Error::InvalidAmount))
+    );
+
+    // A scalar at or above the group order can never verify
+    let malformed = BytesN::from_array(&env, &[0xffu8; 64]);
+    assert_eq!(
+        client.try_claim_with_signature(&claim, &malformed, &relayer),
+        Err(Ok(Error::InvalidSignature))
+    );
+    assert_eq!(client.get_claim_nonce(&vault_id), 0);
+}
+
+#[test]
+fn admin_recovery_revokes_the_claim_signer() {
+    let (env, client, _, vault_id, key) = setup();
+    let recipient = Address::generate(&env);
+    let relayer = Address::generate(&env);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 500);
+
+    let (claim, signature) = signed(&client, &key, vault_id, 0, &recipient, 0);
+    client.transfer_beneficiary(&vault_id, &Address::generate(&env));
+    assert_eq!(client.get_claim_signer(&vault_id), None);
+    assert_eq!(
+        client.try_claim_with_signature(&claim, &signature, &relayer),
+        Err(Ok(Error::NoClaimSigner))
+    );
+    assert_eq!(client.get_vault(&vault_id).released_amount, 0);
+}
@@ -0,0 +1,34 @@
+use soroban_sdk::{
+    testutils::{storage::Persistent as _, Address as _},
+    Address,
+};
+
+use vesting_contracts::{DataKey, PERSISTENT_TTL_EXTEND_TO};
+
+mod common;
+use common::{create, setup, Setup};
+
+#[test]
+fn vault_records_live_in_persistent_storage() {
+    let Setup { env, client, .. } = setup();
+    let contract_id = client.address.clone();
+
+    let beneficiary = Address::generate(&env);
+    let vault_id = create(&client, &beneficiary, 1_000, 0);
+
+    env.as_contract(&contract_id, || {
+        let vault_key = DataKey::VaultData(vault_id);
+        let user_key = DataKey::UserVaults(beneficiary.clone());
+
+        assert!(!env.storage().instance().has(&vault_key));
+        assert!(!env.storage().instance().has(&user_key));
+        assert!(env.storage().persistent().has(&vault_key));
+        assert!(env.storage().persistent().has(&user_key));
+
+        assert_eq!(
+            env.storage().persistent().get_ttl(&vault_key),
+            PERSISTENT_TTL_EXTEND_TO
+        );
+        assert!(env.storage().instance().has(&DataKey::VaultCount));
+    });
+}
@@ -0,0 +1,108 @@
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address,
+};
+
+use vesting_contracts::{VaultFilter, MAX_PAGE_SIZE};
+
+mod common;
+use common::{create, setup, Setup};
+
+fn no_filter() -> VaultFilter {
+    VaultFilter {
+        owner: None,
+        frozen: None,
+        irrevocable: None,
+        fully_released: None,
+    }
+}
+
+#[test]
+fn list_vaults_pages_with_cursor() {
+    let Setup { env, client, .. } = setup();
+    let owner = Address::generate(&env);
+    for _ in 0..5 {
+        create(&client, &owner, 1_000, 0);
+    }
+
+    let first = client.list_vaults(&0u64, &2u32, &no_filter());
+    assert_eq!(first.vault_ids, vec![&env, 1u64, 2u64]);
+    assert_eq!(first.next_cursor, Some(2));
+
+    let second = client.list_vaults(&2u64, &2u32, &no_filter());
+    assert_eq!(second.vault_ids, vec![&env, 3u64, 4u64]);
+
+    let last = client.list_vaults(&4u64, &2u32, &no_filter());
+    assert_eq!(last.vault_ids, vec![&env, 5u64]);
+    assert_eq!(last.next_cursor, None);
+}
+
+#[test]
+fn list_vaults_applies_filters() {
+    let Setup { env, client, .. } = setup();
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let a1 = create(&client, &alice, 1_000, 0);
+    let b1 = create(&client, &bob, 1_000, 0);
+    let a2 = create(&client, &alice, 1_000, 0);
+
+    client.freeze_vault(&a2);
+    client.mark_irrevocable(&b1);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1_000);
+    client.claim_tokens(&a1, &1_000i128);
+
+    let by_owner = VaultFilter {
+        owner: Some(alice.clone()),
+        ..no_filter()
+    };
+    assert_eq!(
+        client.list_vaults(&0u64, &10u32, &by_owner).vault_ids,
+        vec![&env, a1, a2]
+    );
+
+    let frozen = VaultFilter {
+        frozen: Some(true),
+        ..no_filter()
+    };
+    assert_eq!(
+        client.list_vaults(&0u64, &10u32, &frozen).vault_ids,
+        vec![&env, a2]
+    );
+
+    let irrevocable = VaultFilter {
+        irrevocable: Some(true),
+        ..no_filter()
+    };
+    assert_eq!(
+        client.list_vaults(&0u64, &10u32, &irrevocable).vault_ids,
+        vec![&env, b1]
+    );
+
+    let still_vesting = VaultFilter {
+        owner: Some(alice),
+        fully_released: Some(false),
+        ..no_filter()
+    };
+    assert_eq!(
+        client.list_vaults(&0u64, &10u32, &still_vesting).vault_ids,
+        vec![&env, a2]
+    );
+}
+
+#[test]
+fn user_vaults_are_paginated() {
+    let Setup { env, client, .. } = setup();
+    let owner = Address::generate(&env);
+    for _ in 0..3 {
+        create(&client, &owner, 1_000, 0);
+    }
+
+    assert_eq!(client.get_user_vault_count(&owner), 3);
+    assert_eq!(
+        client.get_user_vaults_page(&owner, &1u32, &MAX_PAGE_SIZE),
+        vec![&env, 2u64, 3u64]
+    );
+    assert!(client
+        .get_user_vaults_page(&owner, &3u32, &10u32)
+        .is_empty());
+}
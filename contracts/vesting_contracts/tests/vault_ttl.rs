@@ -0,0 +1,130 @@
+use soroban_sdk::{
+    testutils::{storage::Persistent as _, Address as _, Ledger},
+    vec, Address,
+};
+
+use vesting_contracts::{DataKey, Error, Milestone, PERSISTENT_TTL_EXTEND_TO};
+
+mod common;
+use common::{create, setup, Setup};
+
+#[test]
+fn anyone_can_extend_vault_and_related_entries() {
+    let Setup { env, client, .. } = setup();
+    let contract_id = client.address.clone();
+    let beneficiary = Address::generate(&env);
+    let vault_id = create(&client, &beneficiary, 1_000, 0);
+    client.set_milestones(
+        &vault_id,
+        &vec![
+            &env,
+            Milestone {
+                id: 1,
+                percentage: 100,
+                is_unlocked: false,
+            },
+        ],
+    );
+    assert_eq!(client.get_vault_ttl(&vault_id), PERSISTENT_TTL_EXTEND_TO);
+
+    env.ledger()
+        .set_sequence_number(env.ledger().sequence() + 1_000);
+    assert_eq!(
+        client.get_vault_ttl(&vault_id),
+        PERSISTENT_TTL_EXTEND_TO - 1_000
+    );
+
+    env.set_auths(&[]);
+    assert_eq!(client.extend_vault_ttl(&vault_id), PERSISTENT_TTL_EXTEND_TO);
+
+    env.as_contract(&contract_id, || {
+        let storage = env.storage().persistent();
+        for key in [
+            DataKey::VaultData(vault_id),
+            DataKey::VaultMilestones(vault_id),
+            DataKey::UserVaults(beneficiary.clone()),
+        ] {
+            assert_eq!(storage.get_ttl(&key), PERSISTENT_TTL_EXTEND_TO);
+        }
+    });
+}
+
+#[test]
+fn audit_entries_are_extended_in_pages_and_archived_history_is_kept() {
+    let Setup { env, client, .. } = setup();
+    let contract_id = client.address.clone();
+    let beneficiary = Address::generate(&env);
+    let vault_id = create(&client, &beneficiary, 1_000, 0);
+    client.freeze_vault(&vault_id);
+    client.unfreeze_vault(&vault_id);
+
+    env.ledger()
+        .set_sequence_number(env.ledger().sequence() + 1_000);
+    // The vault's own keys are extended, its audit entries are left to the paged call
+    client.extend_vault_ttl(&vault_id);
+    env.as_contract(&contract_id, || {
+        assert_eq!(
+            env.storage().persistent().get_ttl(&DataKey::AuditEntry(1)),
+            PERSISTENT_TTL_EXTEND_TO - 1_000
+        );
+    });
+    assert_eq!(
+        client.extend_vault_audit_ttl(&vault_id, &0u32, &2u32),
+        Some(2)
+    );
+    assert_eq!(client.extend_vault_audit_ttl(&vault_id, &2u32, &2u32), None);
+    env.as_contract(&contract_id, || {
+        let storage = env.storage().persistent();
+        for id in 1..=3u64 {
+            assert_eq!(
+                storage.get_ttl(&DataKey::AuditEntry(id)),
+                PERSISTENT_TTL_EXTEND_TO
+            );
+        }
+    });
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1_000);
+    client.claim_tokens(&vault_id, &1_000i128);
+    client.archive_vault(&vault_id);
+    env.ledger()
+        .set_sequence_number(env.ledger().sequence() + 1_000);
+    assert_eq!(client.extend_vault_ttl(&vault_id), PERSISTENT_TTL_EXTEND_TO);
+    assert_eq!(
+        client.extend_vault_audit_ttl(&vault_id, &0u32, &10u32),
+        None
+    );
+    env.as_contract(&contract_id, || {
+        let storage = env.storage().persistent();
+        for key in [
+            DataKey::ArchivedVault(vault_id),
+            DataKey::VaultAuditLog(vault_id),
+            DataKey::AuditEntry(1),
+        ] {
+            assert_eq!(storage.get_ttl(&key), PERSISTENT_TTL_EXTEND_TO);
+        }
+    });
+}
+
+#[test]
+fn claims_refresh_vault_ttl() {
+    let Setup { env, client, .. } = setup();
+    let beneficiary = Address::generate(&env);
+    let vault_id = create(&client, &beneficiary, 1_000, 0);
+
+    env.ledger()
+        .set_sequence_number(env.ledger().sequence() + 5_000);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1_000);
+    client.claim_tokens(&vault_id, &100i128);
+
+    assert_eq!(client.get_vault_ttl(&vault_id), PERSISTENT_TTL_EXTEND_TO);
+}
+
+#[test]
+fn extending_unknown_vault_fails() {
+    let Setup { env: _, client, .. } = setup();
+
+    assert_eq!(
+        client.try_extend_vault_ttl(&7u64),
+        Err(Ok(Error::VaultNotFound))
+    );
+}
@@ -0,0 +1,434 @@
+use stellar_xdr::curr::{
+    ContractEvent, ContractEventBody, ContractEventType, ScAddress, ScMap, ScVal,
+};
+
+use crate::state::MilestoneState;
+use crate::ReplayError;
+
+// Payload versions this crate decodes, oldest to newest (`EVENT_VERSION` in
+// the contract). Version 1 streams are rejected: their `milestones_set` does
+// not carry the milestones and lazy vaults are initialized without an event,
+// so neither can be rebuilt. Versions 2 and 3 differ only in the unit of
+// `keeper_fee_sponsored.amount`, which carries no replayed state.
+pub const OLDEST_EVENT_VERSION: u32 = 2;
+pub const SUPPORTED_EVENT_VERSION: u32 = 3;
+
+// One contract event as it travels over the wire: topics plus data, still
+// undecoded. Built from test-environment XDR or from exported RPC JSON.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RawEvent {
+    pub topics: Vec<ScVal>,
+    pub data: ScVal,
+}
+
+impl RawEvent {
+    // Returns `None` for system and diagnostic events, which carry no state
+    pub fn from_xdr(event: &ContractEvent) -> Option<Self> {
+        if event.type_ != ContractEventType::Contract {
+            return None;
+        }
+        let ContractEventBody::V0(body) = &event.body;
+        Some(RawEvent {
+            topics: body.topics.to_vec(),
+            data: body.data.clone(),
+        })
+    }
+}
+
+// Decoded vesting contract event. Field names mirror the contract's
+// `#[contractevent]` structs; addresses are kept as XDR `ScAddress`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum VaultEvent {
+    VaultCreated {
+        vault_id: u64,
+        owner: ScAddress,
+        total_amount: i128,
+        keeper_fee: i128,
+        title: String,
+        start_time: u64,
+        end_time: u64,
+        step_duration: u64,
+        creation_time: u64,
+        is_initialized: bool,
+        is_irrevocable: bool,
+        is_transferable: bool,
+    },
+    VaultInitialized {
+        vault_id: u64,
+    },
+    VaultTitleSet {
+        vault_id: u64,
+        title: String,
+    },
+    TokensClaimed {
+        vault_id: u64,
+        unstaked: i128,
+        released_total: i128,
+    },
+    TokensStaked {
+        vault_id: u64,
+        staked_total: i128,
+    },
+    TokensRevoked {
+        vault_id: u64,
+        amount: i128,
+        released_total: i128,
+    },
+    VaultClawedBack {
+        vault_id: u64,
+        amount: i128,
+    },
+    VaultFrozen {
+        vault_id: u64,
+        reason_code: u32,
+        expires_at: Option<u64>,
+    },
+    VaultUnfrozen {
+        vault_id: u64,
+    },
+    IrrevocableMarked {
+        vault_id: u64,
+    },
+    BeneficiaryChanged {
+        vault_id: u64,
+        new_owner: ScAddress,
+    },
+    DelegateUpdated {
+        vault_id: u64,
+        new_delegate: Option<ScAddress>,
+    },
+    DelegateAdded {
+        vault_id: u64,
+        delegate: ScAddress,
+    },
+    DelegateRemoved {
+        vault_id: u64,
+        delegate: ScAddress,
+    },
+    MilestonesSet {
+        vault_id: u64,
+        milestones: Vec<MilestoneState>,
+    },
+    MilestoneUnlocked {
+        vault_id: u64,
+        milestone_id: u64,
+    },
+    VaultArchived {
+        vault_id: u64,
+    },
+    CohortCreated {
+        cohort_id: u64,
+        vault_ids: Vec<u64>,
+    },
+    PauseToggled {
+        paused: bool,
+    },
+    PauseScheduled {
+        expires_at: u64,
+    },
+    ScopePauseSet {
+        scope: String,
+        paused: bool,
+    },
+    SchemaMigrated {
+        to_version: u32,
+    },
+    ContractDeprecated {
+        target: ScAddress,
+    },
+    // Events that carry no vault or accounting state of their own: summaries
+    // of per-vault events already in the stream, payouts and migrations
+    Informational {
+        name: String,
+    },
+}
+
+// Events the replayer accepts without deriving state from them
+const INFORMATIONAL: &[&str] = &[
+    "keeper_fee_paid",
+    "batch_auto_claimed",
+    "keeper_fee_configured",
+    "keeper_fee_sponsored",
+    "keeper_fees_withdrawn",
+    "keeper_pool_funded",
+    "keeper_pool_withdrawn",
+    "keeper_sponsorship_set",
+    "auto_claim_policy_set",
+    "delegate_permissions_set",
+    "claim_signer_set",
+    "signed_claim_relayed",
+    "claim_callback_invoked",
+    "batch_revoked",
+    "cohort_frozen",
+    "cohort_irrevocable_marked",
+    "cohort_milestone_unlocked",
+    "cohort_revoked",
+    "vault_migrated",
+    "contract_upgraded",
+    "liquidity_migrated",
+    "rescue_executed",
+    "payout_address_set",
+];
+
+impl VaultEvent {
+    pub fn decode(raw: &RawEvent) -> Result<Self, ReplayError> {
+        let name = match raw.topics.first() {
+            Some(ScVal::Symbol(sym)) => sym.to_utf8_string_lossy(),
+            _ => return Err(ReplayError::Malformed("missing event name topic".into())),
+        };
+        let data = Fields::new(&name, &raw.data)?;
+        let version = data.u32("version")?;
+        if !(OLDEST_EVENT_VERSION..=SUPPORTED_EVENT_VERSION).contains(&version) {
+            return Err(ReplayError::UnsupportedVersion { name, version });
+        }
+
+        let event = match name.as_str() {
+            "vault_created" => VaultEvent::VaultCreated {
+                vault_id: topic_u64(raw, &name, 1)?,
+                owner: topic_address(raw, &name, 2)?,
+                total_amount: data.i128("total_amount")?,
+                keeper_fee: data.i128("keeper_fee")?,
+                title: data.string("title")?,
+                start_time: data.u64("start_time")?,
+                end_time: data.u64("end_time")?,
+                step_duration: data.u64("step_duration")?,
+                creation_time: data.u64("creation_time")?,
+                is_initialized: data.bool("is_initialized")?,
+                is_irrevocable: data.bool("is_irrevocable")?,
+                is_transferable: data.bool("is_transferable")?,
+            },
+            "vault_initialized" => VaultEvent::VaultInitialized {
+                vault_id: topic_u64(raw, &name, 1)?,
+            },
+            "vault_title_set" => VaultEvent::VaultTitleSet {
+                vault_id: topic_u64(raw, &name, 1)?,
+                title: data.string("title")?,
+            },
+            "tokens_claimed" => VaultEvent::TokensClaimed {
+                vault_id: topic_u64(raw, &name, 1)?,
+                unstaked: data.i128("unstaked")?,
+                released_total: data.i128("released_total")?,
+            },
+            "tokens_staked" => VaultEvent::TokensStaked {
+                vault_id: topic_u64(raw, &name, 1)?,
+                staked_total: data.i128("staked_total")?,
+            },
+            "tokens_revoked" => VaultEvent::TokensRevoked {
+                vault_id: topic_u64(raw, &name, 1)?,
+                amount: data.i128("amount")?,
+                released_total: data.i128("released_total")?,
+            },
+            "vault_clawed_back" => VaultEvent::VaultClawedBack {
+                vault_id: topic_u64(raw, &name, 1)?,
+                amount: data.i128("amount")?,
+            },
+            "vault_frozen" => VaultEvent::VaultFrozen {
+                vault_id: topic_u64(raw, &name, 1)?,
+                reason_code: data.u32("reason_code")?,
+                expires_at: data.opt_u64("expires_at")?,
+            },
+            "vault_unfrozen" => VaultEvent::VaultUnfrozen {
+                vault_id: topic_u64(raw, &name, 1)?,
+            },
+            "irrevocable_marked" => VaultEvent::IrrevocableMarked {
+                vault_id: topic_u64(raw, &name, 1)?,
+            },
+            "beneficiary_updated" | "beneficiary_rotated" => VaultEvent::BeneficiaryChanged {
+                vault_id: topic_u64(raw, &name, 1)?,
+                new_owner: data.address("new_owner")?,
+            },
+            "delegate_updated" => VaultEvent::DelegateUpdated {
+                vault_id: topic_u64(raw, &name, 1)?,
+                new_delegate: data.opt_address("new_delegate")?,
+            },
+            "delegate_added" => VaultEvent::DelegateAdded {
+                vault_id: topic_u64(raw, &name, 1)?,
+                delegate: topic_address(raw, &name, 2)?,
+            },
+            "delegate_removed" => VaultEvent::DelegateRemoved {
+                vault_id: topic_u64(raw, &name, 1)?,
+                delegate: topic_address(raw, &name, 2)?,
+            },
+            "milestones_set" => VaultEvent::MilestonesSet {
+                vault_id: topic_u64(raw, &name, 1)?,
+                milestones: data.milestones("milestones")?,
+            },
+            "milestone_unlocked" => VaultEvent::MilestoneUnlocked {
+                vault_id: topic_u64(raw, &name, 1)?,
+                milestone_id: data.u64("milestone_id")?,
+            },
+            "vault_archived" => VaultEvent::VaultArchived {
+                vault_id: topic_u64(raw, &name, 1)?,
+            },
+            "cohort_created" => VaultEvent::CohortCreated {
+                cohort_id: topic_u64(raw, &name, 1)?,
+                vault_ids: data.vec_u64("vault_ids")?,
+            },
+            "pause_toggled" => VaultEvent::PauseToggled {
+                paused: data.bool("paused")?,
+            },
+            "pause_scheduled" => VaultEvent::PauseScheduled {
+                expires_at: data.u64("expires_at")?,
+            },
+            "scope_pause_set" => VaultEvent::ScopePauseSet {
+                scope: data.unit_enum("scope")?,
+                paused: data.bool("paused")?,
+            },
+            "schema_migrated" => VaultEvent::SchemaMigrated {
+                to_version: data.u32("to_version")?,
+            },
+            "contract_deprecated" => VaultEvent::ContractDeprecated {
+                target: data.address("target")?,
+            },
+            other if INFORMATIONAL.contains(&other) => VaultEvent::Informational { name },
+            _ => return Err(ReplayError::UnknownEvent(name)),
+        };
+        Ok(event)
+    }
+}
+
+fn topic_u64(raw: &RawEvent, name: &str, index: usize) -> Result<u64, ReplayError> {
+    match raw.topics.get(index) {
+        Some(ScVal::U64(v)) => Ok(*v),
+        _ => Err(ReplayError::Malformed(format!(
+            "{name}: topic {index} is not a u64"
+        ))),
+    }
+}
+
+fn topic_address(raw: &RawEvent, name: &str, index: usize) -> Result<ScAddress, ReplayError> {
+    match raw.topics.get(index) {
+        Some(ScVal::Address(a)) => Ok(a.clone()),
+        _ => Err(ReplayError::Malformed(format!(
+            "{name}: topic {index} is not an address"
+        ))),
+    }
+}
+
+// Typed accessors over an event's data map
+struct Fields<'a> {
+    name: &'a str,
+    map: &'a ScMap,
+}
+
+impl<'a> Fields<'a> {
+    fn new(name: &'a str, data: &'a ScVal) -> Result<Self, ReplayError> {
+        match data {
+            ScVal::Map(Some(map)) => Ok(Fields { name, map }),
+            _ => Err(ReplayError::Malformed(format!("{name}: data is not a map"))),
+        }
+    }
+
+    fn get(&self, key: &str) -> Result<&'a ScVal, ReplayError> {
+        self.map
+            .iter()
+            .find(
+                |entry| matches!(&entry.key, ScVal::Symbol(s) if s.0.as_slice() == key.as_bytes()),
+            )
+            .map(|entry| &entry.val)
+            .ok_or_else(|| ReplayError::Malformed(format!("{}: missing field {key}", self.name)))
+    }
+
+    fn wrong_type(&self, key: &str) -> ReplayError {
+        ReplayError::Malformed(format!("{}: field {key} has an unexpected type", self.name))
+    }
+
+    fn u32(&self, key: &str) -> Result<u32, ReplayError> {
+        match self.get(key)? {
+            ScVal::U32(v) => Ok(*v),
+            _ => Err(self.wrong_type(key)),
+        }
+    }
+
+    fn u64(&self, key: &str) -> Result<u64, ReplayError> {
+        match self.get(key)? {
+            ScVal::U64(v) => Ok(*v),
+            _ => Err(self.wrong_type(key)),
+        }
+    }
+
+    fn opt_u64(&self, key: &str) -> Result<Option<u64>, ReplayError> {
+        match self.get(key)? {
+            ScVal::Void => Ok(None),
+            ScVal::U64(v) => Ok(Some(*v)),
+            _ => Err(self.wrong_type(key)),
+        }
+    }
+
+    fn i128(&self, key: &str) -> Result<i128, ReplayError> {
+        match self.get(key)? {
+            ScVal::I128(parts) => Ok(((parts.hi as i128) << 64) | parts.lo as i128),
+            _ => Err(self.wrong_type(key)),
+        }
+    }
+
+    fn bool(&self, key: &str) -> Result<bool, ReplayError> {
+        match self.get(key)? {
+            ScVal::Bool(v) => Ok(*v),
+            _ => Err(self.wrong_type(key)),
+        }
+    }
+
+    fn string(&self, key: &str) -> Result<String, ReplayError> {
+        match self.get(key)? {
+            ScVal::String(s) => Ok(s.to_utf8_string_lossy()),
+            _ => Err(self.wrong_type(key)),
+        }
+    }
+
+    fn address(&self, key: &str) -> Result<ScAddress, ReplayError> {
+        match self.get(key)? {
+            ScVal::Address(a) => Ok(a.clone()),
+            _ => Err(self.wrong_type(key)),
+        }
+    }
+
+    fn opt_address(&self, key: &str) -> Result<Option<ScAddress>, ReplayError> {
+        match self.get(key)? {
+            ScVal::Void => Ok(None),
+            ScVal::Address(a) => Ok(Some(a.clone())),
+            _ => Err(self.wrong_type(key)),
+        }
+    }
+
+    fn vec_u64(&self, key: &str) -> Result<Vec<u64>, ReplayError> {
+        match self.get(key)? {
+            ScVal::Vec(Some(items)) => items
+                .iter()
+                .map(|item| match item {
+                    ScVal::U64(v) => Ok(*v),
+                    _ => Err(self.wrong_type(key)),
+                })
+                .collect(),
+            _ => Err(self.wrong_type(key)),
+        }
+    }
+
+    fn milestones(&self, key: &str) -> Result<Vec<MilestoneState>, ReplayError> {
+        match self.get(key)? {
+            ScVal::Vec(Some(items)) => items
+                .iter()
+                .map(|item| {
+                    let m = Fields::new(self.name, item)?;
+                    Ok(MilestoneState {
+                        id: m.u64("id")?,
+                        percentage: m.u32("percentage")?,
+                        is_unlocked: m.bool("is_unlocked")?,
+                    })
+                })
+                .collect(),
+            _ => Err(self.wrong_type(key)),
+        }
+    }
+
+    // `#[contracttype]` unit enum variants are encoded as `[Symbol]`
+    fn unit_enum(&self, key: &str) -> Result<String, ReplayError> {
+        match self.get(key)? {
+            ScVal::Vec(Some(items)) => match items.first() {
+                Some(ScVal::Symbol(sym)) if items.len() == 1 => Ok(sym.to_utf8_string_lossy()),
+                _ => Err(self.wrong_type(key)),
+            },
+            _ => Err(self.wrong_type(key)),
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use stellar_xdr::curr::{Limits, ReadXdr, ScVal, WriteXdr};
+
+use crate::event::RawEvent;
+use crate::ReplayError;
+
+// One event in the shape Stellar RPC `getEvents` returns: topics and value
+// as base64-encoded `ScVal` XDR. Other fields RPC adds (ledger, id, ...)
+// are ignored on input.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RpcEvent {
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    kind: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    in_successful_contract_call: Option<bool>,
+    topic: Vec<String>,
+    value: String,
+}
+
+fn decode_scval(encoded: &str) -> Result<ScVal, ReplayError> {
+    ScVal::from_xdr_base64(encoded, Limits::none())
+        .map_err(|e| ReplayError::Json(format!("invalid ScVal XDR: {e}")))
+}
+
+fn encode_scval(val: &ScVal) -> Result<String, ReplayError> {
+    val.to_xdr_base64(Limits::none())
+        .map_err(|e| ReplayError::Json(format!("cannot encode ScVal: {e}")))
+}
+
+// Accepts a bare array of events, a `getEvents` result (`{"events": [...]}`)
+// or the full JSON-RPC response (`{"result": {"events": [...]}}`).
+pub fn parse_events(json: &str) -> Result<Vec<RawEvent>, ReplayError> {
+    let root: Value = serde_json::from_str(json).map_err(|e| ReplayError::Json(e.to_string()))?;
+    let list = match &root {
+        Value::Array(_) => &root,
+        Value::Object(obj) => obj
+            .get("result")
+            .and_then(|r| r.get("events"))
+            .or_else(|| obj.get("events"))
+            .ok_or_else(|| ReplayError::Json("no events array found".into()))?,
+        _ => return Err(ReplayError::Json("expected an array or object".into())),
+    };
+    let events: Vec<RpcEvent> =
+        serde_json::from_value(list.clone()).map_err(|e| ReplayError::Json(e.to_string()))?;
+
+    let mut raw = Vec::with_capacity(events.len());
+    for event in events {
+        if event.kind.as_deref().is_some_and(|k| k != "contract") {
+            continue;
+        }
+        if event.in_successful_contract_call == Some(false) {
+            continue;
+        }
+        raw.push(RawEvent {
+            topics: event
+                .topic
+                .iter()
+                .map(|t| decode_scval(t))
+                .collect::<Result<_, _>>()?,
+            data: decode_scval(&event.value)?,
+        });
+    }
+    Ok(raw)
+}
+
+// Serializes events as a JSON array `parse_events` reads back unchanged
+pub fn export_events(events: &[RawEvent]) -> Result<String, ReplayError> {
+    let rpc = events
+        .iter()
+        .map(|event| {
+            Ok(RpcEvent {
+                kind: Some("contract".into()),
+                in_successful_contract_call: None,
+                topic: event
+                    .topics
+                    .iter()
+                    .map(encode_scval)
+                    .collect::<Result<_, ReplayError>>()?,
+                value: encode_scval(&event.data)?,
+            })
+        })
+        .collect::<Result<Vec<_>, ReplayError>>()?;
+    serde_json::to_string_pretty(&rpc).map_err(|e| ReplayError::Json(e.to_string()))
+}
@@ -0,0 +1,294 @@
+use std::collections::BTreeMap;
+
+use stellar_xdr::curr::ScAddress;
+
+use crate::event::VaultEvent;
+use crate::ReplayError;
+
+// Per-vault state rebuilt from events. Mirrors the contract's `Vault` view,
+// plus the freeze, milestone and cohort details the events carry.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VaultState {
+    pub owner: ScAddress,
+    pub delegate: Option<ScAddress>,
+    // Every delegate, primary first, as `get_delegates` returns them
+    pub delegates: Vec<ScAddress>,
+    pub title: String,
+    pub total_amount: i128,
+    pub released_amount: i128,
+    pub staked_amount: i128,
+    pub keeper_fee: i128,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub creation_time: u64,
+    pub step_duration: u64,
+    pub is_initialized: bool,
+    pub is_irrevocable: bool,
+    pub is_transferable: bool,
+    pub is_frozen: bool,
+    // Reason and expiry of the freeze in force, if any. A freeze whose expiry
+    // has passed stays recorded here, as it does on chain.
+    pub freeze_reason: Option<u32>,
+    pub freeze_expires_at: Option<u64>,
+    pub milestones: Vec<MilestoneState>,
+    pub cohort_id: Option<u64>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MilestoneState {
+    pub id: u64,
+    pub percentage: u32,
+    pub is_unlocked: bool,
+}
+
+// Contract-wide state. `locked`, `released`, `revoked` and `staked` match
+// `get_accounting_totals`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GlobalState {
+    pub vault_count: u64,
+    pub cohort_count: u64,
+    pub locked: i128,
+    pub released: i128,
+    pub revoked: i128,
+    pub staked: i128,
+    pub paused: bool,
+    pub pause_expires_at: Option<u64>,
+    pub paused_scopes: BTreeMap<String, bool>,
+    pub schema_version: Option<u32>,
+    pub deprecated: bool,
+    pub migration_target: Option<ScAddress>,
+}
+
+// Everything rebuilt so far. Applying the same events in the same order
+// always yields the same state.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ReplayState {
+    pub vaults: BTreeMap<u64, VaultState>,
+    // Final snapshot of every archived vault, as it stood when archived
+    pub archived: BTreeMap<u64, VaultState>,
+    pub global: GlobalState,
+    pub events_applied: u64,
+}
+
+impl ReplayState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn vault(&self, vault_id: u64) -> Option<&VaultState> {
+        self.vaults.get(&vault_id)
+    }
+
+    // IDs of live vaults owned by `owner`, in ascending order
+    pub fn vaults_of(&self, owner: &ScAddress) -> Vec<u64> {
+        self.vaults
+            .iter()
+            .filter(|(_, v)| &v.owner == owner)
+            .map(|(id, _)| *id)
+            .collect()
+    }
+
+    fn vault_mut(&mut self, vault_id: u64) -> Result<&mut VaultState, ReplayError> {
+        self.vaults
+            .get_mut(&vault_id)
+            .ok_or(ReplayError::UnknownVault(vault_id))
+    }
+
+    pub fn apply(&mut self, event: &VaultEvent) -> Result<(), ReplayError> {
+        match event {
+            VaultEvent::VaultCreated {
+                vault_id,
+                owner,
+                total_amount,
+                keeper_fee,
+                title,
+                start_time,
+                end_time,
+                step_duration,
+                creation_time,
+                is_initialized,
+                is_irrevocable,
+                is_transferable,
+            } => {
+                if self.vaults.contains_key(vault_id) || self.archived.contains_key(vault_id) {
+                    return Err(ReplayError::DuplicateVault(*vault_id));
+                }
+                self.vaults.insert(
+                    *vault_id,
+                    VaultState {
+                        owner: owner.clone(),
+                        delegate: None,
+                        delegates: Vec::new(),
+                        title: title.clone(),
+                        total_amount: *total_amount,
+                        released_amount: 0,
+                        staked_amount: 0,
+                        keeper_fee: *keeper_fee,
+                        start_time: *start_time,
+                        end_time: *end_time,
+                        creation_time: *creation_time,
+                        step_duration: *step_duration,
+                        is_initialized: *is_initialized,
+                        is_irrevocable: *is_irrevocable,
+                        is_transferable: *is_transferable,
+                        is_frozen: false,
+                        freeze_reason: None,
+                        freeze_expires_at: None,
+                        milestones: Vec::new(),
+                        cohort_id: None,
+                    },
+                );
+                self.global.vault_count = self.global.vault_count.max(*vault_id);
+                self.global.locked += total_amount;
+            }
+            VaultEvent::VaultInitialized { vault_id } => {
+                self.vault_mut(*vault_id)?.is_initialized = true;
+            }
+            VaultEvent::VaultTitleSet { vault_id, title } => {
+                self.vault_mut(*vault_id)?.title = title.clone();
+            }
+            VaultEvent::TokensClaimed {
+                vault_id,
+                unstaked,
+                released_total,
+            } => {
+                let vault = self.vault_mut(*vault_id)?;
+                let released = released_total - vault.released_amount;
+                vault.released_amount = *released_total;
+                vault.staked_amount -= unstaked;
+                self.global.released += released;
+                self.global.locked -= released;
+                self.global.staked -= unstaked;
+            }
+            VaultEvent::TokensStaked {
+                vault_id,
+                staked_total,
+            } => {
+                let vault = self.vault_mut(*vault_id)?;
+                let staked = staked_total - vault.staked_amount;
+                vault.staked_amount = *staked_total;
+                self.global.staked += staked;
+            }
+            VaultEvent::TokensRevoked {
+                vault_id,
+                amount,
+                released_total,
+            } => {
+                self.vault_mut(*vault_id)?.released_amount = *released_total;
+                self.global.revoked += amount;
+                self.global.locked -= amount;
+            }
+            VaultEvent::VaultClawedBack { vault_id, amount } => {
+                let vault = self.vault_mut(*vault_id)?;
+                vault.released_amount = vault.total_amount;
+                self.global.revoked += amount;
+                self.global.locked -= amount;
+            }
+            VaultEvent::VaultFrozen {
+                vault_id,
+                reason_code,
+                expires_at,
+            } => {
+                let vault = self.vault_mut(*vault_id)?;
+                vault.is_frozen = true;
+                vault.freeze_reason = Some(*reason_code);
+                vault.freeze_expires_at = *expires_at;
+            }
+            VaultEvent::VaultUnfrozen { vault_id } => {
+                let vault = self.vault_mut(*vault_id)?;
+                vault.is_frozen = false;
+                vault.freeze_reason = None;
+                vault.freeze_expires_at = None;
+            }
+            VaultEvent::IrrevocableMarked { vault_id } => {
+                self.vault_mut(*vault_id)?.is_irrevocable = true;
+            }
+            VaultEvent::BeneficiaryChanged {
+                vault_id,
+                new_owner,
+            } => {
+                self.vault_mut(*vault_id)?.owner = new_owner.clone();
+            }
+            VaultEvent::DelegateUpdated {
+                vault_id,
+                new_delegate,
+            } => {
+                // `set_delegate` replaces the whole set without a
+                // `delegate_added` for the new primary
+                let vault = self.vault_mut(*vault_id)?;
+                match new_delegate {
+                    Some(d) if !vault.delegates.contains(d) => vault.delegates = vec![d.clone()],
+                    Some(_) => {}
+                    None => vault.delegates.clear(),
+                }
+                vault.delegate = new_delegate.clone();
+            }
+            VaultEvent::DelegateAdded { vault_id, delegate } => {
+                self.vault_mut(*vault_id)?.delegates.push(delegate.clone());
+            }
+            VaultEvent::DelegateRemoved { vault_id, delegate } => {
+                self.vault_mut(*vault_id)?
+                    .delegates
+                    .retain(|d| d != delegate);
+            }
+            VaultEvent::MilestonesSet {
+                vault_id,
+                milestones,
+            } => {
+                self.vault_mut(*vault_id)?.milestones = milestones.clone();
+            }
+            VaultEvent::MilestoneUnlocked {
+                vault_id,
+                milestone_id,
+            } => {
+                let vault = self.vault_mut(*vault_id)?;
+                let milestone = vault
+                    .milestones
+                    .iter_mut()
+                    .find(|m| m.id == *milestone_id)
+                    .ok_or(ReplayError::UnknownMilestone {
+                        vault_id: *vault_id,
+                        milestone_id: *milestone_id,
+                    })?;
+                milestone.is_unlocked = true;
+            }
+            VaultEvent::VaultArchived { vault_id } => {
+                let vault = self
+                    .vaults
+                    .remove(vault_id)
+                    .ok_or(ReplayError::UnknownVault(*vault_id))?;
+                self.archived.insert(*vault_id, vault);
+            }
+            VaultEvent::CohortCreated {
+                cohort_id,
+                vault_ids,
+            } => {
+                for vault_id in vault_ids {
+                    self.vault_mut(*vault_id)?.cohort_id = Some(*cohort_id);
+                }
+                self.global.cohort_count = self.global.cohort_count.max(*cohort_id);
+            }
+            VaultEvent::PauseToggled { paused } => {
+                self.global.paused = *paused;
+                self.global.pause_expires_at = None;
+            }
+            VaultEvent::PauseScheduled { expires_at } => {
+                self.global.paused = true;
+                self.global.pause_expires_at = Some(*expires_at);
+            }
+            VaultEvent::ScopePauseSet { scope, paused } => {
+                self.global.paused_scopes.insert(scope.clone(), *paused);
+            }
+            VaultEvent::SchemaMigrated { to_version } => {
+                self.global.schema_version = Some(*to_version);
+            }
+            VaultEvent::ContractDeprecated { target } => {
+                self.global.deprecated = true;
+                self.global.migration_target = Some(target.clone());
+            }
+            VaultEvent::Informational { .. } => {}
+        }
+        self.events_applied += 1;
+        Ok(())
+    }
+}
@@ -0,0 +1,355 @@
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as _, Events, Ledger},
+    token, vec,
+    xdr::{ContractEvent, ContractEventBody, ScAddress, ScMap, ScVal},
+    Address, Env, String,
+};
+use vesting_contracts::{
+    BatchCreateData, Milestone, PauseScope, VestingContract, VestingContractClient,
+};
+use vesting_replay::{
+    export_events, replay_json, replay_xdr, RawEvent, ReplayError, ReplayState,
+    OLDEST_EVENT_VERSION, SUPPORTED_EVENT_VERSION,
+};
+
+#[contract]
+pub struct MockStaking;
+
+#[contractimpl]
+impl MockStaking {
+    pub fn stake(_env: Env, _vault_id: u64, _amount: i128, _validator: Address) {}
+    pub fn unstake(_env: Env, _vault_id: u64, _amount: i128) {}
+}
+
+// Drives the contract and keeps every event it publishes. The test
+// environment only exposes the events of the latest invocation, so each call
+// goes through `call` to be recorded.
+struct Harness {
+    env: Env,
+    client: VestingContractClient<'static>,
+    log: Vec<ContractEvent>,
+}
+
+impl Harness {
+    fn new() -> Self {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().set_timestamp(1_000);
+
+        let contract_id = env.register(VestingContract, ());
+        let client = VestingContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        client.initialize(&admin, &1_000_000i128);
+        let token_addr = env
+            .register_stellar_asset_contract_v2(admin.clone())
+            .address();
+        client.set_token(&token_addr);
+        token::StellarAssetClient::new(&env, &token_addr).mint(&contract_id, &1_000_000i128);
+
+        Harness {
+            env,
+            client,
+            log: Vec::new(),
+        }
+    }
+
+    fn call<T>(&mut self, f: impl FnOnce(&VestingContractClient<'static>) -> T) -> T {
+        let result = f(&self.client);
+        let events = self
+            .env
+            .events()
+            .all()
+            .filter_by_contract(&self.client.address);
+        self.log.extend(events.events().iter().cloned());
+        result
+    }
+
+    fn now(&self) -> u64 {
+        self.env.ledger().timestamp()
+    }
+
+    fn create(&mut self, owner: &Address, amount: i128, duration: u64, fee: i128) -> u64 {
+        let now = self.now();
+        self.call(|c| {
+            c.create_vault_full(
+                owner,
+                &amount,
+                &now,
+                &(now + duration),
+                &fee,
+                &true,
+                &true,
+                &0u64,
+            )
+        })
+    }
+
+    // Read every view the replay should reproduce, then compare. Views go
+    // through `call` too, since reading a lazy vault initializes it.
+    fn assert_replay_matches_chain(&mut self) -> ReplayState {
+        let known = replay_xdr(&self.log).unwrap();
+        let ids: Vec<u64> = (1..=known.global.vault_count).collect();
+        let views: Vec<_> = ids
+            .iter()
+            .map(|id| self.call(|c| c.try_get_vault(id)))
+            .collect();
+        let totals = self.call(|c| c.get_accounting_totals());
+        let paused = self.call(|c| c.is_paused());
+
+        let state = replay_xdr(&self.log).unwrap();
+        assert_eq!(state.global.vault_count, known.global.vault_count);
+        assert_eq!(state.global.locked, totals.locked);
+        assert_eq!(state.global.released, totals.released);
+        assert_eq!(state.global.revoked, totals.revoked);
+        assert_eq!(state.global.staked, totals.staked);
+        assert_eq!(state.global.paused, paused);
+
+        for (id, view) in ids.iter().zip(views) {
+            let Ok(Ok(vault)) = view else {
+                assert!(state.vault(*id).is_none());
+                let archived = self.call(|c| c.get_archived_vault(id)).unwrap();
+                let replayed = &state.archived[id];
+                assert_eq!(replayed.owner, ScAddress::from(&archived.owner));
+                assert_eq!(replayed.total_amount, archived.total_amount);
+                continue;
+            };
+            let replayed = state.vault(*id).unwrap();
+            assert_eq!(replayed.owner, ScAddress::from(&vault.owner));
+            assert_eq!(
+                replayed.delegate,
+                vault.delegate.as_ref().map(ScAddress::from)
+            );
+            let delegates: Vec<ScAddress> = self
+                .call(|c| c.get_delegates(id))
+                .iter()
+                .map(|d| ScAddress::from(&d))
+                .collect();
+            assert_eq!(replayed.delegates, delegates);
+            assert_eq!(String::from_str(&self.env, &replayed.title), vault.title);
+            assert_eq!(replayed.total_amount, vault.total_amount);
+            assert_eq!(replayed.released_amount, vault.released_amount);
+            assert_eq!(replayed.staked_amount, vault.staked_amount);
+            assert_eq!(replayed.keeper_fee, vault.keeper_fee);
+            assert_eq!(replayed.start_time, vault.start_time);
+            assert_eq!(replayed.end_time, vault.end_time);
+            assert_eq!(replayed.creation_time, vault.creation_time);
+            assert_eq!(replayed.step_duration, vault.step_duration);
+            assert_eq!(replayed.is_initialized, vault.is_initialized);
+            assert_eq!(replayed.is_irrevocable, vault.is_irrevocable);
+            assert_eq!(replayed.is_transferable, vault.is_transferable);
+            assert_eq!(replayed.is_frozen, vault.is_frozen);
+
+            let milestones = self.call(|c| c.get_milestones(id));
+            assert_eq!(replayed.milestones.len() as u32, milestones.len());
+            for (r, m) in replayed.milestones.iter().zip(milestones.iter()) {
+                assert_eq!(
+                    (r.id, r.percentage, r.is_unlocked),
+                    (m.id, m.percentage, m.is_unlocked)
+                );
+            }
+            let freeze = self.call(|c| c.get_freeze_record(id));
+            assert_eq!(replayed.freeze_reason, freeze.map(|f| f.reason_code));
+            assert_eq!(replayed.cohort_id, self.call(|c| c.get_vault_cohort(id)));
+
+            let mut owned: Vec<u64> = self
+                .call(|c| c.get_user_vaults_page(&vault.owner, &0u32, &100u32))
+                .iter()
+                .collect();
+            owned.sort();
+            assert_eq!(owned, state.vaults_of(&ScAddress::from(&vault.owner)));
+        }
+        state
+    }
+}
+
+#[test]
+fn replay_matches_chain_after_full_lifecycle() {
+    let mut h = Harness::new();
+    let env = h.env.clone();
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let carol = Address::generate(&env);
+    let delegate = Address::generate(&env);
+    let keeper = Address::generate(&env);
+    let now = h.now();
+
+    let v1 = h.create(&alice, 1_000, 1_000, 10);
+    let v2 = h.create(&bob, 2_000, 2_000, 0);
+    let v3 = h.call(|c| {
+        c.create_vault_lazy(
+            &carol,
+            &500i128,
+            &now,
+            &(now + 1_000),
+            &0i128,
+            &false,
+            &false,
+            &0u64,
+        )
+    });
+    let batch = BatchCreateData {
+        recipients: vec![&env, Address::generate(&env), Address::generate(&env)],
+        amounts: vec![&env, 300i128, 400i128],
+        start_times: vec![&env, now, now],
+        end_times: vec![&env, now + 1_000, now + 1_000],
+        keeper_fees: vec![&env, 0i128, 0i128],
+        step_durations: vec![&env, 0u64, 0u64],
+    };
+    let cohort = h.call(|c| c.batch_create_vaults_full(&batch));
+    let (v4, v5) = (cohort.get(0).unwrap(), cohort.get(1).unwrap());
+    let v6 = h.create(&carol, 100, 1_000, 0);
+    h.call(|c| c.clawback_vault(&v6));
+
+    h.call(|c| c.set_vault_title(&v1, &String::from_str(&env, "Founder")));
+    h.call(|c| c.set_delegate(&v1, &Some(delegate.clone())));
+    let staking = env.register(MockStaking, ());
+    h.call(|c| c.set_staking_contract(&staking));
+    h.call(|c| c.stake_tokens(&v2, &1_800i128, &Address::generate(&env)));
+
+    env.ledger().set_timestamp(now + 500);
+    h.call(|c| c.claim_tokens(&v1, &200i128));
+    h.call(|c| c.claim_as_delegate(&v1, &100i128));
+    // Only 200 of v2 is liquid, so this claim unstakes the rest
+    h.call(|c| c.claim_tokens(&v2, &400i128));
+    h.call(|c| c.revoke_partial(&v2, &100i128));
+    h.call(|c| c.mark_irrevocable(&v2));
+
+    h.call(|c| {
+        c.set_milestones(
+            &v4,
+            &vec![
+                &env,
+                Milestone {
+                    id: 1,
+                    percentage: 50,
+                    is_unlocked: false,
+                },
+                Milestone {
+                    id: 2,
+                    percentage: 50,
+                    is_unlocked: false,
+                },
+            ],
+        )
+    });
+    h.call(|c| c.unlock_milestone(&v4, &1u64));
+    h.call(|c| c.freeze_vault_with_reason(&v5, &7u32, &None, &Some(now + 10_000)));
+
+    h.call(|c| c.transfer_vault(&v1, &Address::generate(&env)));
+    h.call(|c| c.auto_claim(&v1, &keeper));
+
+    h.call(|c| c.revoke_tokens(&v4));
+    h.call(|c| c.archive_vault(&v4));
+    h.call(|c| c.set_scope_paused(&PauseScope::Staking, &true));
+
+    env.ledger().set_timestamp(now + 2_000);
+    h.call(|c| c.get_vault(&v3));
+    h.call(|c| c.claim_tokens(&v3, &500i128));
+
+    let state = h.assert_replay_matches_chain();
+    assert_eq!(state.archived.len(), 1);
+    assert_eq!(state.global.cohort_count, 1);
+    assert_eq!(state.global.paused_scopes.get("Staking"), Some(&true));
+    assert_eq!(state.vault(v1).unwrap().title, "Founder");
+    assert!(state.vault(v1).unwrap().delegate.is_none());
+}
+
+#[test]
+fn exported_json_replays_to_the_same_state() {
+    let mut h = Harness::new();
+    let env = h.env.clone();
+    let owner = Address::generate(&env);
+    let vault_id = h.create(&owner, 1_000, 1_000, 0);
+    h.call(|c| c.freeze_vault(&vault_id));
+    h.call(|c| c.unfreeze_vault(&vault_id));
+    env.ledger().set_timestamp(h.now() + 250);
+    h.call(|c| c.claim_tokens(&vault_id, &250i128));
+
+    let from_xdr = h.assert_replay_matches_chain();
+
+    let raw: Vec<RawEvent> = h.log.iter().filter_map(RawEvent::from_xdr).collect();
+    let exported = export_events(&raw).unwrap();
+    assert_eq!(replay_json(&exported).unwrap(), from_xdr);
+
+    // The same events wrapped in a JSON-RPC `getEvents` response
+    let response = format!(r#"{{"jsonrpc":"2.0","id":1,"result":{{"events":{exported}}}}}"#);
+    assert_eq!(replay_json(&response).unwrap(), from_xdr);
+}
+
+#[test]
+fn delegate_sets_replay_through_adds_removals_and_transfers() {
+    let mut h = Harness::new();
+    let env = h.env.clone();
+    let owner = Address::generate(&env);
+    let (d1, d2, d3) = (
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    );
+    let v1 = h.create(&owner, 1_000, 1_000, 0);
+    let v2 = h.create(&owner, 1_000, 1_000, 0);
+
+    h.call(|c| c.add_delegate(&v1, &d1, &None));
+    h.call(|c| c.add_delegate(&v1, &d2, &None));
+    h.call(|c| c.add_delegate(&v1, &d3, &None));
+    // Removing the primary promotes the next delegate
+    h.call(|c| c.remove_delegate(&v1, &d1));
+    h.assert_replay_matches_chain();
+
+    h.call(|c| c.add_delegate(&v2, &d1, &None));
+    h.call(|c| c.add_delegate(&v2, &d2, &None));
+    h.call(|c| c.set_delegate(&v2, &Some(d3.clone())));
+    h.call(|c| c.add_delegate(&v2, &d1, &None));
+    h.call(|c| c.transfer_vault(&v1, &Address::generate(&env)));
+
+    let state = h.assert_replay_matches_chain();
+    assert!(state.vault(v1).unwrap().delegates.is_empty());
+    assert_eq!(
+        state.vault(v2).unwrap().delegates,
+        std::vec![ScAddress::from(&d3), ScAddress::from(&d1)]
+    );
+}
+
+// The first event of a one-vault stream, with its payload version replaced
+fn vault_created_with_version(version: u32) -> ContractEvent {
+    let mut h = Harness::new();
+    let owner = Address::generate(&h.env);
+    h.create(&owner, 1_000, 1_000, 0);
+
+    let mut event = h.log[0].clone();
+    let ContractEventBody::V0(body) = &mut event.body;
+    let ScVal::Map(Some(map)) = &body.data else {
+        panic!("event data is not a map");
+    };
+    let mut entries = map.0.to_vec();
+    for entry in entries.iter_mut() {
+        if entry.key == ScVal::Symbol("version".try_into().unwrap()) {
+            entry.val = ScVal::U32(version);
+        }
+    }
+    body.data = ScVal::Map(Some(ScMap(entries.try_into().unwrap())));
+    event
+}
+
+#[test]
+fn events_with_unknown_version_are_rejected() {
+    for version in [1, SUPPORTED_EVENT_VERSION + 1] {
+        assert_eq!(
+            replay_xdr(&[vault_created_with_version(version)]),
+            Err(ReplayError::UnsupportedVersion {
+                name: "vault_created".into(),
+                version,
+            })
+        );
+    }
+}
+
+#[test]
+fn every_decodable_version_is_accepted() {
+    for version in OLDEST_EVENT_VERSION..=SUPPORTED_EVENT_VERSION {
+        let state = replay_xdr(&[vault_created_with_version(version)]).unwrap();
+        assert_eq!(state.vault(1).unwrap().total_amount, 1_000);
+    }
+}